@@ -0,0 +1,93 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use backend_service_networking::{RegistryValidationOptions, ServiceMeshRegistry};
+
+const SERVICES: usize = 4_000;
+const API_CONTRACTS_PER_SERVICE: usize = 16;
+
+struct PeakTrackingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakTrackingAllocator {
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8 {
+        let live_bytes = LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK_BYTES.fetch_max(live_bytes, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+    ) {
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakTrackingAllocator = PeakTrackingAllocator;
+
+fn measure(
+    label: &str,
+    load: impl FnOnce() -> ServiceMeshRegistry,
+) {
+    let live_bytes_before = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(live_bytes_before, Ordering::Relaxed);
+    let started_at = Instant::now();
+    let registry = black_box(load());
+    let elapsed = started_at.elapsed();
+    let peak_bytes = PEAK_BYTES.load(Ordering::Relaxed) - live_bytes_before;
+    let retained_bytes = LIVE_BYTES.load(Ordering::Relaxed) - live_bytes_before;
+    println!(
+        "{label}: {} services loaded in {elapsed:?}, peak heap {:.1} MiB, retained {:.1} MiB",
+        registry.service_count(),
+        peak_bytes as f64 / (1024.0 * 1024.0),
+        retained_bytes as f64 / (1024.0 * 1024.0)
+    );
+}
+
+fn large_registry_json() -> String {
+    let services = (0..SERVICES)
+        .map(|service_index| {
+            let api_contracts = (0..API_CONTRACTS_PER_SERVICE)
+                .map(|contract_index| format!("\"worldbuilder.bench{service_index}.operation{contract_index}.v1\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{{\"service_name\": \"backend-bench-{service_index}\", \"base_url\": \"http://backend-bench-{service_index}.infrastructure.svc.cluster.local:8790\", \"api_contracts\": [{api_contracts}]}}"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!("{{\"version\": \"2026-03-01\", \"services\": [\n{services}\n]}}")
+}
+
+fn main() {
+    let registry_path = env::temp_dir().join(format!("backend-service-networking-bench-registry-{}.json", std::process::id()));
+    let registry_json = large_registry_json();
+    fs::write(&registry_path, &registry_json).expect("failed to write bench registry");
+    println!("registry file: {:.1} MiB", registry_json.len() as f64 / (1024.0 * 1024.0));
+    drop(registry_json);
+    let validation_options = RegistryValidationOptions::default();
+
+    measure("read_to_string + from_json_str", || {
+        let registry_json = fs::read_to_string(&registry_path).expect("failed to read bench registry");
+        ServiceMeshRegistry::from_json_str_with_options(&registry_json, &validation_options).expect("failed to load bench registry")
+    });
+    measure("from_file_path (streamed)", || {
+        ServiceMeshRegistry::from_file_path_with_options(&registry_path, &validation_options).expect("failed to load bench registry")
+    });
+
+    fs::remove_file(registry_path).ok();
+}
@@ -0,0 +1,122 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use backend_service_networking::{
+    API_DISCOVERY_SCHEMA_V1, ApiContractIndexKind, KNOWN_API_CONTRACTS, MVP_ANON_2D_GATEWAY_API_CONTRACTS, ResolvedTargetCache, ServiceMeshRegistry,
+    ServiceMeshRegistryBuilder, ServiceMeshRegistryDocument,
+};
+
+const ITERATIONS: usize = 1_000_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+    ) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn measure(
+    label: &str,
+    mut resolve: impl FnMut(),
+) {
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let started_at = Instant::now();
+    for _ in 0..ITERATIONS {
+        resolve();
+    }
+    let elapsed = started_at.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    println!(
+        "{label}: {ITERATIONS} resolutions in {elapsed:?} ({:.1} ns/op), {allocations} allocations",
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let registry = ServiceMeshRegistry::single_service(
+        "2026-02-21",
+        "backend-data-center",
+        "https://backend-data-center.infrastructure.svc.cluster.local:8790",
+        MVP_ANON_2D_GATEWAY_API_CONTRACTS,
+    )
+    .expect("failed to build registry");
+
+    measure("resolve_api_contract", || {
+        black_box(
+            registry
+                .resolve_api_contract(black_box(API_DISCOVERY_SCHEMA_V1))
+                .unwrap(),
+        );
+    });
+    measure("resolve_api_contract_ref", || {
+        black_box(
+            registry
+                .resolve_api_contract_ref(black_box(API_DISCOVERY_SCHEMA_V1))
+                .unwrap(),
+        );
+    });
+
+    let known_contracts_registry_json = format!(
+        "{{\"version\": \"2026-02-21\", \"services\": [{{\"service_name\": \"backend-data-center\", \"base_url\": \"http://backend-data-center.infrastructure.svc.cluster.local:8790\", \"api_contracts\": {}}}]}}",
+        serde_json::to_string(&KNOWN_API_CONTRACTS).expect("failed to encode contracts")
+    );
+    let known_contracts_registry_document =
+        serde_json::from_str::<ServiceMeshRegistryDocument>(&known_contracts_registry_json).expect("failed to decode registry document");
+    for (index_label, api_contract_index_kind) in [
+        ("hashed", ApiContractIndexKind::Hashed),
+        ("sorted", ApiContractIndexKind::Sorted),
+    ] {
+        let known_contracts_registry = ServiceMeshRegistryBuilder::new(known_contracts_registry_document.clone())
+            .api_contract_index(api_contract_index_kind)
+            .build()
+            .expect("failed to build registry");
+        let mut contract_cursor = 0;
+        measure(
+            &format!("service_name_for_contract ({index_label} index, {} contracts)", KNOWN_API_CONTRACTS.len()),
+            || {
+                contract_cursor = (contract_cursor + 1) % KNOWN_API_CONTRACTS.len();
+                black_box(known_contracts_registry.service_name_for_contract(black_box(KNOWN_API_CONTRACTS[contract_cursor])));
+            },
+        );
+        measure(
+            &format!("resolve_api_contract_ref ({index_label} index, {} contracts)", KNOWN_API_CONTRACTS.len()),
+            || {
+                contract_cursor = (contract_cursor + 1) % KNOWN_API_CONTRACTS.len();
+                black_box(
+                    known_contracts_registry
+                        .resolve_api_contract_ref(black_box(KNOWN_API_CONTRACTS[contract_cursor]))
+                        .unwrap(),
+                );
+            },
+        );
+    }
+
+    let resolved_target_cache = ResolvedTargetCache::new(registry);
+    measure("resolve_cached", || {
+        black_box(
+            resolved_target_cache
+                .resolve_cached(black_box(API_DISCOVERY_SCHEMA_V1))
+                .unwrap(),
+        );
+    });
+}
@@ -0,0 +1,31 @@
+use backend_service_networking::{MVP_ANON_2D_GATEWAY_API_CONTRACTS, MeshRegistryError, ServiceMeshRegistry, require_contracts};
+
+require_contracts! {
+    const GATEWAY_REQUIRED_API_CONTRACTS = [
+        API_DISCOVERY_HOME_FEED_V1,
+        API_DISCOVERY_CATALOG_V1,
+        API_DISCOVERY_DETAIL_V1,
+        API_DISCOVERY_SCHEMA_V1,
+        API_DISCOVERY_PLAY_SESSION_GET_V1,
+        API_DISCOVERY_PUBLISH_CREATE_V1,
+        API_PROPERTY_MAP_LOAD_V1,
+        API_PROPERTY_MAP_SAVE_V1,
+        API_AUTH_REGISTER_V1,
+        API_AUTH_LOGIN_V1,
+        API_AUTH_REFRESH_V1,
+        API_AUTH_GUEST_UPGRADE_V1,
+    ];
+    fn ensure_gateway_contracts_registered;
+}
+
+fn main() -> Result<(), MeshRegistryError> {
+    let registry = ServiceMeshRegistry::from_environment_or_single_service(
+        "2026-02-21",
+        "backend-data-center",
+        "https://backend-data-center.infrastructure.svc.cluster.local:8790",
+        MVP_ANON_2D_GATEWAY_API_CONTRACTS,
+    )?;
+    ensure_gateway_contracts_registered(&registry)?;
+    println!("all {} gateway contracts are registered", GATEWAY_REQUIRED_API_CONTRACTS.len());
+    Ok(())
+}
@@ -0,0 +1,25 @@
+#![no_main]
+
+use backend_service_networking::{RegistryValidationOptions, ServiceMeshRegistry, validate_json_str_with_report};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|registry_bytes: &[u8]| {
+    let Ok(registry_json) = std::str::from_utf8(registry_bytes) else {
+        return;
+    };
+    let strict_options = RegistryValidationOptions {
+        allow_unix_socket_targets: true,
+        deny_unknown_fields: true,
+        ..RegistryValidationOptions::strict()
+    };
+
+    if let Ok(registry) = ServiceMeshRegistry::from_json_str(registry_json) {
+        let _ = registry.to_canonical_json();
+        let _ = registry.validate_contract_dependencies();
+        for resolved_target in &registry {
+            let _ = registry.resolve_api_contract(resolved_target.api_contract);
+        }
+    }
+    let _ = ServiceMeshRegistry::from_json_str_with_options(registry_json, &strict_options);
+    let _ = validate_json_str_with_report(registry_json, &strict_options);
+});
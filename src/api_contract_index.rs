@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::collections::hash_map;
+use std::slice;
+
+/// Lookup structure a registry builds for contract resolution; `Sorted` trades hashing for a binary search over one
+/// contiguous allocation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApiContractIndexKind {
+    #[default]
+    Hashed,
+    Sorted,
+}
+
+/// Contract -> service index lookup built as `ApiContractIndexKind` selects.
+#[derive(Debug)]
+pub(crate) enum ApiContractIndex {
+    Hashed(HashMap<String, usize>),
+    Sorted(Vec<(Box<str>, usize)>),
+}
+
+impl ApiContractIndex {
+    pub(crate) fn build(
+        api_contract_to_service_index: HashMap<String, usize>,
+        api_contract_index_kind: ApiContractIndexKind,
+    ) -> Self {
+        if api_contract_index_kind == ApiContractIndexKind::Hashed {
+            return Self::Hashed(api_contract_to_service_index);
+        }
+        let mut entries = api_contract_to_service_index
+            .into_iter()
+            .map(|(api_contract, service_index)| (api_contract.into_boxed_str(), service_index))
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+        Self::Sorted(entries)
+    }
+
+    pub(crate) fn kind(&self) -> ApiContractIndexKind {
+        match self {
+            Self::Hashed(_) => ApiContractIndexKind::Hashed,
+            Self::Sorted(_) => ApiContractIndexKind::Sorted,
+        }
+    }
+
+    pub(crate) fn get_key_value(
+        &self,
+        api_contract: &str,
+    ) -> Option<(&str, &usize)> {
+        match self {
+            Self::Hashed(entries) => entries
+                .get_key_value(api_contract)
+                .map(|(registered_api_contract, service_index)| (registered_api_contract.as_str(), service_index)),
+            Self::Sorted(entries) => entries
+                .binary_search_by(|(registered_api_contract, _)| registered_api_contract.as_ref().cmp(api_contract))
+                .ok()
+                .map(|position| {
+                    let (registered_api_contract, service_index) = &entries[position];
+                    (registered_api_contract.as_ref(), service_index)
+                }),
+        }
+    }
+
+    pub(crate) fn get(
+        &self,
+        api_contract: &str,
+    ) -> Option<&usize> {
+        self.get_key_value(api_contract)
+            .map(|(_, service_index)| service_index)
+    }
+
+    pub(crate) fn contains_key(
+        &self,
+        api_contract: &str,
+    ) -> bool {
+        self.get_key_value(api_contract).is_some()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Hashed(entries) => entries.len(),
+            Self::Sorted(entries) => entries.len(),
+        }
+    }
+
+    pub(crate) fn iter(&self) -> ApiContractIndexIter<'_> {
+        match self {
+            Self::Hashed(entries) => ApiContractIndexIter::Hashed(entries.iter()),
+            Self::Sorted(entries) => ApiContractIndexIter::Sorted(entries.iter()),
+        }
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(api_contract, _)| api_contract)
+    }
+}
+
+pub(crate) enum ApiContractIndexIter<'a> {
+    Hashed(hash_map::Iter<'a, String, usize>),
+    Sorted(slice::Iter<'a, (Box<str>, usize)>),
+}
+
+impl<'a> Iterator for ApiContractIndexIter<'a> {
+    type Item = (&'a str, &'a usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Hashed(entries) => entries
+                .next()
+                .map(|(api_contract, service_index)| (api_contract.as_str(), service_index)),
+            Self::Sorted(entries) => entries
+                .next()
+                .map(|(api_contract, service_index)| (api_contract.as_ref(), service_index)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Hashed(entries) => entries.size_hint(),
+            Self::Sorted(entries) => entries.size_hint(),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::extract::FromRequestParts;
+use axum::response::{IntoResponse, Response};
+use http::StatusCode;
+use http::request::Parts;
+use tower::{Layer, Service};
+
+use crate::error::MeshRegistryError;
+use crate::models::ResolvedServiceTarget;
+use crate::registry::ServiceMeshRegistry;
+
+pub trait ApiContract {
+    const API_CONTRACT: &'static str;
+}
+
+#[derive(Clone, Debug)]
+pub struct ServiceMeshRegistryLayer {
+    registry: Arc<ServiceMeshRegistry>,
+}
+
+impl ServiceMeshRegistryLayer {
+    pub fn new(registry: impl Into<Arc<ServiceMeshRegistry>>) -> Self {
+        Self { registry: registry.into() }
+    }
+}
+
+impl<S> Layer<S> for ServiceMeshRegistryLayer {
+    type Service = ServiceMeshRegistryService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ServiceMeshRegistryService {
+            inner,
+            registry: Arc::clone(&self.registry),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ServiceMeshRegistryService<S> {
+    inner: S,
+    registry: Arc<ServiceMeshRegistry>,
+}
+
+impl<S, B> Service<http::Request<B>> for ServiceMeshRegistryService<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(
+        &mut self,
+        context: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(context)
+    }
+
+    fn call(
+        &mut self,
+        mut request: http::Request<B>,
+    ) -> Self::Future {
+        request.extensions_mut().insert(Arc::clone(&self.registry));
+        self.inner.call(request)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolveContract<C> {
+    pub target: ResolvedServiceTarget,
+    api_contract: PhantomData<fn() -> C>,
+}
+
+impl<C> ResolveContract<C> {
+    pub fn into_inner(self) -> ResolvedServiceTarget {
+        self.target
+    }
+}
+
+impl<C> Deref for ResolveContract<C> {
+    type Target = ResolvedServiceTarget;
+
+    fn deref(&self) -> &Self::Target {
+        &self.target
+    }
+}
+
+impl<C, S> FromRequestParts<S> for ResolveContract<C>
+where
+    C: ApiContract,
+    S: Send + Sync,
+{
+    type Rejection = ResolveContractRejection;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let registry = parts
+            .extensions
+            .get::<Arc<ServiceMeshRegistry>>()
+            .ok_or(ResolveContractRejection::MissingRegistry)?;
+        let target = registry
+            .resolve_api_contract(C::API_CONTRACT)
+            .map_err(ResolveContractRejection::Resolution)?;
+        Ok(Self {
+            target,
+            api_contract: PhantomData,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ResolveContractRejection {
+    MissingRegistry,
+    Resolution(MeshRegistryError),
+}
+
+impl ResolveContractRejection {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ResolveContractRejection::MissingRegistry => StatusCode::INTERNAL_SERVER_ERROR,
+            ResolveContractRejection::Resolution(MeshRegistryError::ServiceDisabled { .. }) => StatusCode::SERVICE_UNAVAILABLE,
+            ResolveContractRejection::Resolution(_) => StatusCode::BAD_GATEWAY,
+        }
+    }
+}
+
+impl IntoResponse for ResolveContractRejection {
+    fn into_response(self) -> Response {
+        let message = match &self {
+            ResolveContractRejection::MissingRegistry => "service mesh registry layer is not installed.".to_string(),
+            ResolveContractRejection::Resolution(resolution_error) => resolution_error.to_string(),
+        };
+        (self.status(), message).into_response()
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+
+const BYTE_SIZE_UNITS: [(&str, u64); 8] = [
+    ("b", 1),
+    ("kb", 1_000),
+    ("mb", 1_000_000),
+    ("gb", 1_000_000_000),
+    ("kib", 1 << 10),
+    ("mib", 1 << 20),
+    ("gib", 1 << 30),
+    ("", 1),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteSizeParseFailure {
+    NotANumber,
+    Negative,
+    Overflow,
+}
+
+impl fmt::Display for ByteSizeParseFailure {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ByteSizeParseFailure::NotANumber => write!(formatter, "not a number"),
+            ByteSizeParseFailure::Negative => write!(formatter, "negative"),
+            ByteSizeParseFailure::Overflow => write!(formatter, "overflows u64"),
+        }
+    }
+}
+
+/// Parses a byte count such as `134217728`, `134_217_728`, `128MiB` or `500 KB` (units are case-insensitive).
+pub(crate) fn parse_byte_size(value: &str) -> Result<u64, ByteSizeParseFailure> {
+    let value = value.trim();
+    if value.starts_with('-') {
+        return Err(ByteSizeParseFailure::Negative);
+    }
+    let digits_end = value
+        .find(|character: char| !character.is_ascii_digit() && character != '_')
+        .unwrap_or(value.len());
+    let (digits, unit) = value.split_at(digits_end);
+    let unit = unit.trim();
+    if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return Err(ByteSizeParseFailure::NotANumber);
+    }
+    let Some((_, unit_multiplier)) = BYTE_SIZE_UNITS
+        .iter()
+        .find(|(unit_name, _)| unit_name.eq_ignore_ascii_case(unit))
+    else {
+        return Err(ByteSizeParseFailure::NotANumber);
+    };
+    let mut byte_count = 0_u64;
+    for digit in digits.bytes().filter(|digit| *digit != b'_') {
+        byte_count = byte_count
+            .checked_mul(10)
+            .and_then(|byte_count| byte_count.checked_add(u64::from(digit - b'0')))
+            .ok_or(ByteSizeParseFailure::Overflow)?;
+    }
+    byte_count
+        .checked_mul(*unit_multiplier)
+        .ok_or(ByteSizeParseFailure::Overflow)
+}
@@ -19,7 +19,37 @@ pub const API_IDENTITY_PROFILE_GET_V1: &str = "worldbuilder.identity.profile.get
 pub const API_IDENTITY_POLICY_EVALUATION_V1: &str = "worldbuilder.identity.policy-evaluation.v1";
 
 pub const ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH: &str = "WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH";
+pub const REGISTRY_DIRECTORY_VERSION_FILE_NAME: &str = "_version.json";
 pub const ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON: &str = "WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON";
+pub const ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY: &str = "WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY";
+pub const ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS: &str = "WORLD_BUILDER_SERVICE_MESH_STRICTNESS";
+pub const ENV_WORLD_BUILDER_ENV: &str = "WORLD_BUILDER_ENV";
+
+pub const SERVICE_METADATA_REGION_KEY: &str = "region";
+pub const UNIX_SOCKET_BASE_URL_SCHEME: &str = "http+unix";
+pub const DEFAULT_SERVICE_ENDPOINT_WEIGHT: u32 = 1;
+pub const DEFAULT_API_CONTRACT_CONTENT_TYPE: &str = "application/json";
+pub const DEFAULT_SERVICE_HEALTH_PATH: &str = "/healthz";
+pub const UNKNOWN_API_CONTRACT_RESOLUTION_STATS_KEY: &str = "<unknown>";
+
+pub const PUBLISH_INGRESS_MAX_BODY_BYTES_SANITY_CEILING: u64 = 1024 * 1024 * 1024;
+
+pub const DEFAULT_MANDATORY_REJECTION_LOG_FIELDS: [&str; 6] = [
+    "publishIngressHop",
+    "configuredMaxBodyBytes",
+    "requiredPolicyBytes",
+    "requestContentLength",
+    "requestId",
+    "apiContract",
+];
+
+pub const DEFAULT_HTTPS_REQUIRED_API_CONTRACT_PREFIXES: [&str; 1] = ["worldbuilder.auth."];
+
+pub const MAX_CLIENT_POLICY_RETRIES: u32 = 10;
+
+pub const DEFAULT_MAX_REGISTRY_SERVICES: usize = 4096;
+pub const DEFAULT_MAX_REGISTRY_TOTAL_API_CONTRACTS: usize = 65536;
+pub const DEFAULT_MAX_REGISTRY_SOURCE_BYTES: usize = 16 << 20;
 
 pub const MVP_ANON_2D_READ_API_CONTRACTS: [&str; 5] = [
     API_DISCOVERY_HOME_FEED_V1,
@@ -44,6 +74,56 @@ pub const MVP_ANON_2D_GATEWAY_API_CONTRACTS: [&str; 12] = [
     API_AUTH_GUEST_UPGRADE_V1,
 ];
 
+const _: () = assert!(
+    api_contract_set_contains_all(&MVP_ANON_2D_GATEWAY_API_CONTRACTS, &MVP_ANON_2D_READ_API_CONTRACTS),
+    "MVP_ANON_2D_GATEWAY_API_CONTRACTS must include every MVP_ANON_2D_READ_API_CONTRACTS entry"
+);
+
+const fn api_contract_set_contains_all(
+    api_contract_set: &[&str],
+    required_api_contracts: &[&str],
+) -> bool {
+    let mut required_index = 0;
+    while required_index < required_api_contracts.len() {
+        if !api_contract_set_contains(api_contract_set, required_api_contracts[required_index]) {
+            return false;
+        }
+        required_index += 1;
+    }
+    true
+}
+
+const fn api_contract_set_contains(
+    api_contract_set: &[&str],
+    api_contract: &str,
+) -> bool {
+    let mut set_index = 0;
+    while set_index < api_contract_set.len() {
+        if str_bytes_equal(api_contract_set[set_index].as_bytes(), api_contract.as_bytes()) {
+            return true;
+        }
+        set_index += 1;
+    }
+    false
+}
+
+const fn str_bytes_equal(
+    left: &[u8],
+    right: &[u8],
+) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut byte_index = 0;
+    while byte_index < left.len() {
+        if left[byte_index] != right[byte_index] {
+            return false;
+        }
+        byte_index += 1;
+    }
+    true
+}
+
 pub const AUTH_STACK_INTERNAL_API_CONTRACTS: [&str; 7] = [
     API_ACCOUNTS_INTERNAL_BOOTSTRAP_V1,
     API_ACCOUNTS_GET_BY_ID_V1,
@@ -53,3 +133,36 @@ pub const AUTH_STACK_INTERNAL_API_CONTRACTS: [&str; 7] = [
     API_IDENTITY_PROFILE_GET_V1,
     API_IDENTITY_POLICY_EVALUATION_V1,
 ];
+
+pub const KNOWN_API_CONTRACTS: [&str; 19] = [
+    API_DISCOVERY_CATALOG_V1,
+    API_DISCOVERY_HOME_FEED_V1,
+    API_DISCOVERY_DETAIL_V1,
+    API_DISCOVERY_SCHEMA_V1,
+    API_DISCOVERY_PLAY_SESSION_GET_V1,
+    API_DISCOVERY_PUBLISH_CREATE_V1,
+    API_PROPERTY_MAP_LOAD_V1,
+    API_PROPERTY_MAP_SAVE_V1,
+    API_AUTH_REGISTER_V1,
+    API_AUTH_LOGIN_V1,
+    API_AUTH_REFRESH_V1,
+    API_AUTH_GUEST_UPGRADE_V1,
+    API_ACCOUNTS_INTERNAL_BOOTSTRAP_V1,
+    API_ACCOUNTS_GET_BY_ID_V1,
+    API_ACCOUNTS_GET_BY_IDENTITY_V1,
+    API_ACCOUNTS_UPDATE_V1,
+    API_IDENTITY_PROFILE_UPSERT_V1,
+    API_IDENTITY_PROFILE_GET_V1,
+    API_IDENTITY_POLICY_EVALUATION_V1,
+];
+
+const _: () = assert!(
+    api_contract_set_contains_all(&KNOWN_API_CONTRACTS, &MVP_ANON_2D_GATEWAY_API_CONTRACTS)
+        && api_contract_set_contains_all(&KNOWN_API_CONTRACTS, &AUTH_STACK_INTERNAL_API_CONTRACTS),
+    "KNOWN_API_CONTRACTS must include every contract set defined by this crate"
+);
+
+/// Whether `api_contract` (trimmed) is one of the crate's `API_*` contract constants.
+pub fn is_known_contract(api_contract: &str) -> bool {
+    api_contract_set_contains(&KNOWN_API_CONTRACTS, api_contract.trim())
+}
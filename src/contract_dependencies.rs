@@ -0,0 +1,49 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+pub(crate) fn find_api_contract_dependency_cycle(api_contract_dependencies: &BTreeMap<&str, Vec<&str>>) -> Option<Vec<String>> {
+    let mut finished_api_contracts = BTreeSet::<&str>::new();
+    let mut dependency_chain = Vec::<&str>::new();
+    for api_contract in api_contract_dependencies.keys() {
+        if let Some(dependency_cycle) = visit_api_contract(api_contract, api_contract_dependencies, &mut finished_api_contracts, &mut dependency_chain) {
+            return Some(dependency_cycle);
+        }
+    }
+    None
+}
+
+fn visit_api_contract<'a>(
+    api_contract: &'a str,
+    api_contract_dependencies: &BTreeMap<&'a str, Vec<&'a str>>,
+    finished_api_contracts: &mut BTreeSet<&'a str>,
+    dependency_chain: &mut Vec<&'a str>,
+) -> Option<Vec<String>> {
+    if finished_api_contracts.contains(api_contract) {
+        return None;
+    }
+    if let Some(cycle_start) = dependency_chain
+        .iter()
+        .position(|chained_api_contract| *chained_api_contract == api_contract)
+    {
+        return Some(
+            dependency_chain[cycle_start..]
+                .iter()
+                .chain(std::iter::once(&api_contract))
+                .map(|chained_api_contract| chained_api_contract.to_string())
+                .collect(),
+        );
+    }
+
+    dependency_chain.push(api_contract);
+    for dependency in api_contract_dependencies
+        .get(api_contract)
+        .into_iter()
+        .flatten()
+    {
+        if let Some(dependency_cycle) = visit_api_contract(dependency, api_contract_dependencies, finished_api_contracts, dependency_chain) {
+            return Some(dependency_cycle);
+        }
+    }
+    dependency_chain.pop();
+    finished_api_contracts.insert(api_contract);
+    None
+}
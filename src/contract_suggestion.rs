@@ -0,0 +1,34 @@
+const MAX_SUGGESTION_EDIT_DISTANCE: usize = 3;
+
+/// Closest candidate within `MAX_SUGGESTION_EDIT_DISTANCE` edits of `api_contract`; ties go to the lexically smallest candidate.
+pub(crate) fn closest_api_contract<'a>(
+    api_contract: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(api_contract, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_EDIT_DISTANCE)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(
+    left: &str,
+    right: &str,
+) -> usize {
+    let right_chars = right.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=right_chars.len()).collect::<Vec<_>>();
+    let mut current_row = vec![0; right_chars.len() + 1];
+    for (left_index, left_char) in left.chars().enumerate() {
+        current_row[0] = left_index + 1;
+        for (right_index, right_char) in right_chars.iter().enumerate() {
+            let substitution_cost = usize::from(left_char != *right_char);
+            current_row[right_index + 1] = (previous_row[right_index] + substitution_cost)
+                .min(previous_row[right_index + 1] + 1)
+                .min(current_row[right_index] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[right_chars.len()]
+}
@@ -0,0 +1,13 @@
+pub fn parse_api_contract_version(api_contract: &str) -> Option<(&str, u32)> {
+    let (api_contract_base, version_suffix) = api_contract.trim().rsplit_once(".v")?;
+    if api_contract_base.is_empty()
+        || version_suffix.is_empty()
+        || !version_suffix
+            .chars()
+            .all(|character| character.is_ascii_digit())
+    {
+        return None;
+    }
+    let version = version_suffix.parse::<u32>().ok()?;
+    Some((api_contract_base, version))
+}
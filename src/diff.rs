@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use crate::models::{ApiContractOwnerChange, PublishIngressPolicy, RegistryDiff, ServiceBaseUrlChange, ServiceRegistration};
+
+pub(crate) fn diff_registry_services(
+    current_services: &[ServiceRegistration],
+    current_publish_ingress_policy: Option<&PublishIngressPolicy>,
+    proposed_services: &[ServiceRegistration],
+    proposed_publish_ingress_policy: Option<&PublishIngressPolicy>,
+) -> RegistryDiff {
+    let current_base_urls = base_urls_by_service_name(current_services);
+    let proposed_base_urls = base_urls_by_service_name(proposed_services);
+    let current_api_contract_owners = api_contract_owners(current_services);
+    let proposed_api_contract_owners = api_contract_owners(proposed_services);
+
+    let mut base_url_changes = Vec::<ServiceBaseUrlChange>::new();
+    for (service_name, current_base_url) in &current_base_urls {
+        let Some(proposed_base_url) = proposed_base_urls.get(service_name) else {
+            continue;
+        };
+        if current_base_url != proposed_base_url {
+            base_url_changes.push(ServiceBaseUrlChange {
+                service_name: service_name.to_string(),
+                current_base_url: current_base_url.to_string(),
+                proposed_base_url: proposed_base_url.to_string(),
+            });
+        }
+    }
+
+    RegistryDiff {
+        services_added: missing_keys(&proposed_base_urls, &current_base_urls),
+        services_removed: missing_keys(&current_base_urls, &proposed_base_urls),
+        api_contracts_added: missing_keys(&proposed_api_contract_owners, &current_api_contract_owners),
+        api_contracts_removed: missing_keys(&current_api_contract_owners, &proposed_api_contract_owners),
+        base_url_changes,
+        api_contract_owner_changes: current_api_contract_owners
+            .iter()
+            .filter_map(|(api_contract, current_service_name)| {
+                let proposed_service_name = proposed_api_contract_owners.get(api_contract)?;
+                (current_service_name != proposed_service_name).then(|| ApiContractOwnerChange {
+                    api_contract: api_contract.to_string(),
+                    current_service_name: current_service_name.to_string(),
+                    proposed_service_name: proposed_service_name.to_string(),
+                })
+            })
+            .collect(),
+        publish_ingress_policy_changed: current_publish_ingress_policy != proposed_publish_ingress_policy,
+    }
+}
+
+/// Renders `registry_diff` as one `+`/`-`/`~` line per change, ordered by category and then by name.
+pub(crate) fn render_registry_changelog(
+    registry_diff: &RegistryDiff,
+    current_services: &[ServiceRegistration],
+    proposed_services: &[ServiceRegistration],
+) -> String {
+    let current_api_contract_owners = api_contract_owners(current_services);
+    let proposed_api_contract_owners = api_contract_owners(proposed_services);
+    let mut changelog_lines = Vec::<String>::new();
+
+    for service_name in &registry_diff.services_added {
+        changelog_lines.push(format!(
+            "+ added service {} serving {}",
+            service_name,
+            owned_api_contracts(&proposed_api_contract_owners, service_name).join(", ")
+        ));
+    }
+    for service_name in &registry_diff.services_removed {
+        changelog_lines.push(format!(
+            "- removed service {} serving {}",
+            service_name,
+            owned_api_contracts(&current_api_contract_owners, service_name).join(", ")
+        ));
+    }
+    for api_contract in &registry_diff.api_contracts_added {
+        let service_name = proposed_api_contract_owners[api_contract.as_str()];
+        if !registry_diff
+            .services_added
+            .iter()
+            .any(|added_service| added_service == service_name)
+        {
+            changelog_lines.push(format!("+ added contract {} to {}", api_contract, service_name));
+        }
+    }
+    for api_contract in &registry_diff.api_contracts_removed {
+        let service_name = current_api_contract_owners[api_contract.as_str()];
+        if !registry_diff
+            .services_removed
+            .iter()
+            .any(|removed_service| removed_service == service_name)
+        {
+            changelog_lines.push(format!("- removed contract {} from {}", api_contract, service_name));
+        }
+    }
+    for owner_change in &registry_diff.api_contract_owner_changes {
+        changelog_lines.push(format!(
+            "~ moved contract {} from {} to {}",
+            owner_change.api_contract, owner_change.current_service_name, owner_change.proposed_service_name
+        ));
+    }
+    for base_url_change in &registry_diff.base_url_changes {
+        changelog_lines.push(format!(
+            "~ base_url changed for {}: {} -> {}",
+            base_url_change.service_name, base_url_change.current_base_url, base_url_change.proposed_base_url
+        ));
+    }
+    if registry_diff.publish_ingress_policy_changed {
+        changelog_lines.push("~ publish_ingress_policy changed".to_string());
+    }
+
+    changelog_lines
+        .iter()
+        .map(|changelog_line| format!("{}\n", changelog_line))
+        .collect()
+}
+
+fn owned_api_contracts<'a>(
+    api_contract_owners: &BTreeMap<&'a str, &str>,
+    service_name: &str,
+) -> Vec<&'a str> {
+    api_contract_owners
+        .iter()
+        .filter(|(_, owner_service_name)| **owner_service_name == service_name)
+        .map(|(api_contract, _)| *api_contract)
+        .collect()
+}
+
+fn base_urls_by_service_name(services: &[ServiceRegistration]) -> BTreeMap<&str, &str> {
+    services
+        .iter()
+        .map(|service| (service.service_name.trim(), service.base_url.trim()))
+        .collect()
+}
+
+fn api_contract_owners(services: &[ServiceRegistration]) -> BTreeMap<&str, &str> {
+    services
+        .iter()
+        .flat_map(|service| {
+            service
+                .api_contracts
+                .iter()
+                .map(|api_contract| (api_contract.trim(), service.service_name.trim()))
+        })
+        .collect()
+}
+
+fn missing_keys(
+    source: &BTreeMap<&str, &str>,
+    target: &BTreeMap<&str, &str>,
+) -> Vec<String> {
+    source
+        .keys()
+        .filter(|key| !target.contains_key(*key))
+        .map(|key| key.to_string())
+        .collect()
+}
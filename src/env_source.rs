@@ -0,0 +1,73 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+
+pub trait EnvSource {
+    fn var(
+        &self,
+        key: &str,
+    ) -> Option<String>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProcessEnv;
+
+impl EnvSource for ProcessEnv {
+    fn var(
+        &self,
+        key: &str,
+    ) -> Option<String> {
+        env::var(key).ok()
+    }
+}
+
+impl EnvSource for HashMap<String, String> {
+    fn var(
+        &self,
+        key: &str,
+    ) -> Option<String> {
+        self.get(key).cloned()
+    }
+}
+
+impl EnvSource for BTreeMap<String, String> {
+    fn var(
+        &self,
+        key: &str,
+    ) -> Option<String> {
+        self.get(key).cloned()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PrefixedEnv<'a, E: EnvSource> {
+    prefix: &'a str,
+    inner: &'a E,
+}
+
+impl<'a, E: EnvSource> PrefixedEnv<'a, E> {
+    pub fn new(
+        prefix: &'a str,
+        inner: &'a E,
+    ) -> Self {
+        Self { prefix, inner }
+    }
+}
+
+impl<E: EnvSource> EnvSource for PrefixedEnv<'_, E> {
+    fn var(
+        &self,
+        key: &str,
+    ) -> Option<String> {
+        self.inner.var(&format!("{}{key}", self.prefix))
+    }
+}
+
+pub(crate) fn env_flag_enabled(
+    env_source: &impl EnvSource,
+    key: &str,
+) -> bool {
+    env_source.var(key).is_some_and(|value| {
+        let normalized_value = value.trim();
+        normalized_value == "1" || normalized_value.eq_ignore_ascii_case("true")
+    })
+}
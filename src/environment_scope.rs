@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::models::ServiceMeshRegistryDocument;
+
+impl ServiceMeshRegistryDocument {
+    /// Drops services and contracts whose `environments` list excludes `environment`, services left with no contracts,
+    /// and every per-contract entry (aliases, routes, kinds, ...) keyed by a dropped contract. Entries without
+    /// `environments` apply everywhere.
+    pub fn scoped_to_environment(
+        mut self,
+        environment: &str,
+    ) -> Self {
+        let environment = environment.trim();
+        let api_contract_environments = self
+            .api_contract_environments
+            .iter()
+            .map(|(api_contract, environments)| (api_contract.trim().to_string(), environments.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let registered_api_contracts = registered_api_contracts_of(&self);
+
+        self.services.retain_mut(|service| {
+            if !applies_to_environment(&service.environments, environment) {
+                return false;
+            }
+            let had_api_contracts = !service.api_contracts.is_empty();
+            service.api_contracts.retain(|api_contract| {
+                api_contract_environments
+                    .get(api_contract.trim())
+                    .is_none_or(|environments| applies_to_environment(environments, environment))
+            });
+            !had_api_contracts || !service.api_contracts.is_empty()
+        });
+        let scoped_out_api_contracts = registered_api_contracts
+            .difference(&registered_api_contracts_of(&self))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
+        let is_in_scope = |api_contract: &String| !scoped_out_api_contracts.contains(api_contract.trim());
+        self.api_contract_aliases
+            .retain(|_, canonical_api_contract| is_in_scope(canonical_api_contract));
+        self.api_contract_dependencies
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_routes
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_kinds
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_enabled_when_env
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_content_types
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_rate_limits
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_requires_auth
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self.api_contract_environments
+            .retain(|api_contract, _| is_in_scope(api_contract));
+        self
+    }
+}
+
+fn registered_api_contracts_of(document: &ServiceMeshRegistryDocument) -> BTreeSet<String> {
+    document
+        .services
+        .iter()
+        .flat_map(|service| service.api_contracts.iter())
+        .map(|api_contract| api_contract.trim().to_string())
+        .collect()
+}
+
+fn applies_to_environment(
+    environments: &[String],
+    environment: &str,
+) -> bool {
+    environments.is_empty()
+        || environments
+            .iter()
+            .any(|scoped_environment| scoped_environment.trim().eq_ignore_ascii_case(environment))
+}
@@ -1,10 +1,45 @@
 use std::fmt;
 
+use crate::byte_size::ByteSizeParseFailure;
+
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MeshRegistryError {
     InvalidDocument(String),
+    VersionMismatch {
+        expected: String,
+        actual: String,
+    },
     UnknownApiContract(String),
+    ServiceDisabled {
+        service_name: String,
+        api_contract: String,
+    },
+    ContractDisabled {
+        api_contract: String,
+        env_var: String,
+    },
+    NoServiceInstanceInRegion {
+        api_contract: String,
+        region: String,
+    },
+    InvalidTargetPath {
+        base_url: String,
+        path: String,
+        message: String,
+    },
     MissingRequiredApiContracts(Vec<String>),
+    MissingApiContractDependency {
+        api_contract: String,
+        dependency: String,
+    },
+    ApiContractDependencyCycle(Vec<String>),
+    OwnershipViolation {
+        api_contract: String,
+        expected_service: String,
+        actual_service: String,
+    },
+    IncompatibleUpgrade(String),
     MissingPublishIngressPolicy,
     MissingPublishIngressHop(String),
     MissingPublishIngressHopLimit {
@@ -15,16 +50,73 @@ pub enum MeshRegistryError {
         hop_name: String,
         env_var: String,
         value: String,
+        reason: ByteSizeParseFailure,
     },
     PublishIngressHopLimitTooLow {
         hop_name: String,
         configured_max_body_bytes: u64,
         required_min_body_bytes: u64,
     },
+    PublishIngressHopLimitErrors(Vec<MeshRegistryError>),
     Decode(String),
     Io(String),
 }
 
+impl MeshRegistryError {
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, MeshRegistryError::Io(_))
+    }
+
+    pub fn is_configuration_error(&self) -> bool {
+        match self {
+            MeshRegistryError::InvalidDocument(_)
+            | MeshRegistryError::VersionMismatch { .. }
+            | MeshRegistryError::UnknownApiContract(_)
+            | MeshRegistryError::ServiceDisabled { .. }
+            | MeshRegistryError::ContractDisabled { .. }
+            | MeshRegistryError::NoServiceInstanceInRegion { .. }
+            | MeshRegistryError::MissingRequiredApiContracts(_)
+            | MeshRegistryError::MissingApiContractDependency { .. }
+            | MeshRegistryError::ApiContractDependencyCycle(_)
+            | MeshRegistryError::OwnershipViolation { .. }
+            | MeshRegistryError::IncompatibleUpgrade(_)
+            | MeshRegistryError::MissingPublishIngressPolicy
+            | MeshRegistryError::MissingPublishIngressHop(_)
+            | MeshRegistryError::MissingPublishIngressHopLimit { .. }
+            | MeshRegistryError::InvalidPublishIngressHopLimit { .. }
+            | MeshRegistryError::PublishIngressHopLimitTooLow { .. }
+            | MeshRegistryError::PublishIngressHopLimitErrors(_)
+            | MeshRegistryError::Decode(_) => true,
+            MeshRegistryError::InvalidTargetPath { .. } | MeshRegistryError::Io(_) => false,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            MeshRegistryError::InvalidDocument(_) => "invalid_document",
+            MeshRegistryError::VersionMismatch { .. } => "version_mismatch",
+            MeshRegistryError::UnknownApiContract(_) => "unknown_api_contract",
+            MeshRegistryError::ServiceDisabled { .. } => "service_disabled",
+            MeshRegistryError::ContractDisabled { .. } => "contract_disabled",
+            MeshRegistryError::NoServiceInstanceInRegion { .. } => "no_service_instance_in_region",
+            MeshRegistryError::InvalidTargetPath { .. } => "invalid_target_path",
+            MeshRegistryError::MissingRequiredApiContracts(_) => "missing_required_api_contracts",
+            MeshRegistryError::MissingApiContractDependency { .. } => "missing_api_contract_dependency",
+            MeshRegistryError::ApiContractDependencyCycle(_) => "api_contract_dependency_cycle",
+            MeshRegistryError::OwnershipViolation { .. } => "ownership_violation",
+            MeshRegistryError::IncompatibleUpgrade(_) => "incompatible_upgrade",
+            MeshRegistryError::MissingPublishIngressPolicy => "missing_publish_ingress_policy",
+            MeshRegistryError::MissingPublishIngressHop(_) => "missing_publish_ingress_hop",
+            MeshRegistryError::MissingPublishIngressHopLimit { .. } => "missing_publish_ingress_hop_limit",
+            MeshRegistryError::InvalidPublishIngressHopLimit { .. } => "invalid_publish_ingress_hop_limit",
+            MeshRegistryError::PublishIngressHopLimitTooLow { .. } => "publish_ingress_hop_limit_too_low",
+            MeshRegistryError::PublishIngressHopLimitErrors(_) => "publish_ingress_hop_limit_errors",
+            MeshRegistryError::Decode(_) => "decode",
+            MeshRegistryError::Io(_) => "io",
+        }
+    }
+}
+
 impl fmt::Display for MeshRegistryError {
     fn fmt(
         &self,
@@ -34,9 +126,34 @@ impl fmt::Display for MeshRegistryError {
             MeshRegistryError::InvalidDocument(message) => {
                 write!(formatter, "invalid service mesh registry: {}.", message)
             }
+            MeshRegistryError::VersionMismatch { expected, actual } => {
+                write!(
+                    formatter,
+                    "service mesh registry version '{}' does not match expected version '{}'.",
+                    actual, expected
+                )
+            }
             MeshRegistryError::UnknownApiContract(api_contract) => {
                 write!(formatter, "service mesh api contract '{}' is not registered.", api_contract)
             }
+            MeshRegistryError::ServiceDisabled { service_name, api_contract } => write!(
+                formatter,
+                "service mesh api contract '{}' is owned by disabled service '{}'.",
+                api_contract, service_name
+            ),
+            MeshRegistryError::ContractDisabled { api_contract, env_var } => write!(
+                formatter,
+                "service mesh api contract '{}' is disabled until env '{}' is set to true.",
+                api_contract, env_var
+            ),
+            MeshRegistryError::NoServiceInstanceInRegion { api_contract, region } => write!(
+                formatter,
+                "service mesh api contract '{}' has no service instance in region '{}'.",
+                api_contract, region
+            ),
+            MeshRegistryError::InvalidTargetPath { base_url, path, message } => {
+                write!(formatter, "failed to join path '{}' onto service base_url '{}': {}.", path, base_url, message)
+            }
             MeshRegistryError::MissingRequiredApiContracts(missing_api_contracts) => {
                 write!(
                     formatter,
@@ -44,6 +161,30 @@ impl fmt::Display for MeshRegistryError {
                     missing_api_contracts.join(", ")
                 )
             }
+            MeshRegistryError::MissingApiContractDependency { api_contract, dependency } => write!(
+                formatter,
+                "service mesh api contract '{}' depends on unregistered api contract '{}'.",
+                api_contract, dependency
+            ),
+            MeshRegistryError::ApiContractDependencyCycle(dependency_cycle) => {
+                write!(
+                    formatter,
+                    "service mesh api contract dependencies form a cycle: {}.",
+                    dependency_cycle.join(" -> ")
+                )
+            }
+            MeshRegistryError::OwnershipViolation {
+                api_contract,
+                expected_service,
+                actual_service,
+            } => write!(
+                formatter,
+                "service mesh api contract '{}' is owned by service '{}', expected '{}'.",
+                api_contract, actual_service, expected_service
+            ),
+            MeshRegistryError::IncompatibleUpgrade(breaking_change) => {
+                write!(formatter, "proposed service mesh registry is not a compatible upgrade: {}.", breaking_change)
+            }
             MeshRegistryError::MissingPublishIngressPolicy => write!(formatter, "service mesh registry is missing publish ingress policy."),
             MeshRegistryError::MissingPublishIngressHop(hop_name) => write!(formatter, "publish ingress policy does not define required hop '{}'.", hop_name),
             MeshRegistryError::MissingPublishIngressHopLimit { hop_name, env_var } => write!(
@@ -51,10 +192,15 @@ impl fmt::Display for MeshRegistryError {
                 "publish ingress hop '{}' is missing configured body limit env '{}'.",
                 hop_name, env_var
             ),
-            MeshRegistryError::InvalidPublishIngressHopLimit { hop_name, env_var, value } => write!(
+            MeshRegistryError::InvalidPublishIngressHopLimit {
+                hop_name,
+                env_var,
+                value,
+                reason,
+            } => write!(
                 formatter,
-                "publish ingress hop '{}' env '{}' must be a positive integer byte value, got '{}'.",
-                hop_name, env_var, value
+                "publish ingress hop '{}' env '{}' must be a positive integer byte value, got '{}' ({}).",
+                hop_name, env_var, value, reason
             ),
             MeshRegistryError::PublishIngressHopLimitTooLow {
                 hop_name,
@@ -65,6 +211,13 @@ impl fmt::Display for MeshRegistryError {
                 "publish ingress hop '{}' max body {} bytes is below required {} bytes.",
                 hop_name, configured_max_body_bytes, required_min_body_bytes
             ),
+            MeshRegistryError::PublishIngressHopLimitErrors(hop_limit_errors) => {
+                let hop_limit_messages = hop_limit_errors
+                    .iter()
+                    .map(|hop_limit_error| hop_limit_error.to_string().trim_end_matches('.').to_string())
+                    .collect::<Vec<_>>();
+                write!(formatter, "publish ingress hop limits are misconfigured: {}.", hop_limit_messages.join("; "))
+            }
             MeshRegistryError::Decode(message) => write!(formatter, "failed to decode service mesh registry document: {}.", message),
             MeshRegistryError::Io(message) => {
                 write!(formatter, "failed to read service mesh registry source: {}.", message)
@@ -74,3 +227,15 @@ impl fmt::Display for MeshRegistryError {
 }
 
 impl std::error::Error for MeshRegistryError {}
+
+impl From<std::io::Error> for MeshRegistryError {
+    fn from(io_error: std::io::Error) -> Self {
+        MeshRegistryError::Io(io_error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for MeshRegistryError {
+    fn from(decode_error: serde_json::Error) -> Self {
+        MeshRegistryError::Decode(decode_error.to_string())
+    }
+}
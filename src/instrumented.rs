@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::constants::UNKNOWN_API_CONTRACT_RESOLUTION_STATS_KEY;
+use crate::error::MeshRegistryError;
+use crate::models::{ContractResolutionStats, ResolvedServiceTarget};
+use crate::registry::ServiceMeshRegistry;
+
+type UnknownContractHook = Box<dyn Fn(&str) + Send + Sync>;
+
+pub struct InstrumentedRegistry {
+    registry: ServiceMeshRegistry,
+    resolution_stats: Mutex<BTreeMap<String, ContractResolutionStats>>,
+    on_unknown_contract: Option<UnknownContractHook>,
+}
+
+impl InstrumentedRegistry {
+    pub fn new(registry: ServiceMeshRegistry) -> Self {
+        Self {
+            registry,
+            resolution_stats: Mutex::new(BTreeMap::new()),
+            on_unknown_contract: None,
+        }
+    }
+
+    pub fn set_on_unknown_contract(
+        &mut self,
+        on_unknown_contract: UnknownContractHook,
+    ) {
+        self.on_unknown_contract = Some(on_unknown_contract);
+    }
+
+    pub fn registry(&self) -> &ServiceMeshRegistry {
+        &self.registry
+    }
+
+    /// Tallies by registered contract name, so alias lookups count toward their canonical contract; names the registry
+    /// does not register (including `default_service` fallbacks) share the `UNKNOWN_API_CONTRACT_RESOLUTION_STATS_KEY`
+    /// entry, keeping the stats map bounded by the registry size.
+    pub fn resolve_api_contract(
+        &self,
+        api_contract: &str,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        let resolution = self.registry.resolve_api_contract(api_contract);
+        {
+            let mut resolution_stats = self
+                .resolution_stats
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let stats_key = self
+                .registry
+                .registered_api_contract(api_contract)
+                .unwrap_or(UNKNOWN_API_CONTRACT_RESOLUTION_STATS_KEY);
+            let contract_stats = resolution_stats.entry(stats_key.to_string()).or_default();
+            if resolution.is_ok() {
+                contract_stats.succeeded += 1;
+            } else {
+                contract_stats.failed += 1;
+            }
+        }
+        if let (Err(MeshRegistryError::UnknownApiContract(unknown_api_contract)), Some(on_unknown_contract)) = (&resolution, &self.on_unknown_contract) {
+            on_unknown_contract(unknown_api_contract);
+        }
+        resolution
+    }
+
+    pub fn resolution_stats(&self) -> BTreeMap<String, ContractResolutionStats> {
+        self.resolution_stats
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    pub fn unresolved_api_contracts(&self) -> Vec<String> {
+        let resolution_stats = self.resolution_stats();
+        let mut unresolved_api_contracts = self
+            .registry
+            .services()
+            .iter()
+            .flat_map(|service| service.api_contracts.iter())
+            .map(|api_contract| api_contract.trim())
+            .filter(|api_contract| {
+                resolution_stats
+                    .get(*api_contract)
+                    .is_none_or(|contract_stats| contract_stats.succeeded == 0)
+            })
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        unresolved_api_contracts.sort();
+        unresolved_api_contracts
+    }
+
+    pub fn into_inner(self) -> ServiceMeshRegistry {
+        self.registry
+    }
+}
+
+impl fmt::Debug for InstrumentedRegistry {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        formatter
+            .debug_struct("InstrumentedRegistry")
+            .field("registry", &self.registry)
+            .field("resolution_stats", &self.resolution_stats)
+            .field("on_unknown_contract", &self.on_unknown_contract.is_some())
+            .finish()
+    }
+}
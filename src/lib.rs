@@ -1,23 +1,67 @@
+mod api_contract_index;
+#[cfg(feature = "axum")]
+mod axum_extract;
+mod byte_size;
 mod constants;
+mod contract_dependencies;
+mod contract_suggestion;
+mod contract_version;
+mod diff;
+mod env_source;
+mod environment_scope;
 mod error;
+mod instrumented;
+mod mesh_tracing;
 mod models;
+#[cfg(feature = "prost")]
+pub mod proto;
 mod registry;
+mod registry_builder;
+mod registry_directory;
+mod require_contracts;
+mod resolve_cache;
+mod route_table;
+mod source_size_limit;
+#[cfg(any(test, feature = "test-support"))]
+mod test_support;
+mod unknown_fields;
 mod validation;
 
+pub use api_contract_index::ApiContractIndexKind;
+#[cfg(feature = "axum")]
+pub use axum_extract::{ApiContract, ResolveContract, ResolveContractRejection, ServiceMeshRegistryLayer, ServiceMeshRegistryService};
+pub use byte_size::ByteSizeParseFailure;
 pub use constants::{
     API_ACCOUNTS_GET_BY_ID_V1, API_ACCOUNTS_GET_BY_IDENTITY_V1, API_ACCOUNTS_INTERNAL_BOOTSTRAP_V1, API_ACCOUNTS_UPDATE_V1, API_AUTH_GUEST_UPGRADE_V1,
     API_AUTH_LOGIN_V1, API_AUTH_REFRESH_V1, API_AUTH_REGISTER_V1, API_DISCOVERY_CATALOG_V1, API_DISCOVERY_DETAIL_V1, API_DISCOVERY_HOME_FEED_V1,
     API_DISCOVERY_PLAY_SESSION_GET_V1, API_DISCOVERY_PUBLISH_CREATE_V1, API_DISCOVERY_SCHEMA_V1, API_IDENTITY_POLICY_EVALUATION_V1,
-    API_IDENTITY_PROFILE_GET_V1, API_IDENTITY_PROFILE_UPSERT_V1, AUTH_STACK_INTERNAL_API_CONTRACTS, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON,
-    ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH, MVP_ANON_2D_GATEWAY_API_CONTRACTS, MVP_ANON_2D_READ_API_CONTRACTS, API_PROPERTY_MAP_LOAD_V1,
-    API_PROPERTY_MAP_SAVE_V1,
+    API_IDENTITY_PROFILE_GET_V1, API_IDENTITY_PROFILE_UPSERT_V1, API_PROPERTY_MAP_LOAD_V1, API_PROPERTY_MAP_SAVE_V1, AUTH_STACK_INTERNAL_API_CONTRACTS,
+    DEFAULT_API_CONTRACT_CONTENT_TYPE, DEFAULT_HTTPS_REQUIRED_API_CONTRACT_PREFIXES, DEFAULT_MANDATORY_REJECTION_LOG_FIELDS, DEFAULT_MAX_REGISTRY_SERVICES,
+    DEFAULT_MAX_REGISTRY_SOURCE_BYTES, DEFAULT_MAX_REGISTRY_TOTAL_API_CONTRACTS, DEFAULT_SERVICE_ENDPOINT_WEIGHT, DEFAULT_SERVICE_HEALTH_PATH,
+    ENV_WORLD_BUILDER_ENV, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH,
+    ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY, ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS, KNOWN_API_CONTRACTS, MAX_CLIENT_POLICY_RETRIES,
+    MVP_ANON_2D_GATEWAY_API_CONTRACTS, MVP_ANON_2D_READ_API_CONTRACTS, PUBLISH_INGRESS_MAX_BODY_BYTES_SANITY_CEILING, REGISTRY_DIRECTORY_VERSION_FILE_NAME,
+    UNIX_SOCKET_BASE_URL_SCHEME, UNKNOWN_API_CONTRACT_RESOLUTION_STATS_KEY, is_known_contract,
 };
+pub use contract_version::parse_api_contract_version;
+pub use env_source::{EnvSource, PrefixedEnv, ProcessEnv};
 pub use error::MeshRegistryError;
+pub use instrumented::InstrumentedRegistry;
 pub use models::{
-    PublishIngressHopRuntimeLimit, PublishIngressObservability, PublishIngressPolicy, PublishIngressRequiredHop, ResolvedServiceTarget,
-    ServiceMeshRegistryDocument, ServiceRegistration,
+    ApiContractKind, ApiContractOwnerChange, ApiContractRoute, ClientPolicy, ContractResolutionStats, PublishIngressHopRuntimeLimit,
+    PublishIngressObservability, PublishIngressPolicy, PublishIngressRequiredHop, RateLimit, RegistryDiff, RegistryMetrics, RegistryValidationOptions,
+    ResolutionExplanation, ResolvedServiceTarget, ResolvedServiceTargetRef, ServiceBaseUrlChange, ServiceInstance, ServiceMeshRegistryDocument,
+    ServiceRegistration, StrictnessProfile, ValidationReport,
+};
+pub use registry::{ResolvedServiceTargets, ServiceMeshRegistry};
+pub use registry_builder::ServiceMeshRegistryBuilder;
+pub use resolve_cache::ResolvedTargetCache;
+#[cfg(any(test, feature = "test-support"))]
+pub use test_support::TestRegistryGuard;
+pub use validation::{
+    validate_all, validate_all_with_options, validate_document, validate_document_with_options, validate_json_str, validate_json_str_with_options,
+    validate_json_str_with_report, validate_with_report, validate_with_report_with_options,
 };
-pub use registry::ServiceMeshRegistry;
 
 #[cfg(test)]
 mod tests;
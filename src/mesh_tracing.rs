@@ -0,0 +1,67 @@
+//! Optional `tracing` instrumentation for registry resolution and publish ingress conformance checks.
+//!
+//! With the `tracing` feature enabled, `ServiceMeshRegistry::resolve_api_contract` runs inside a
+//! `mesh.resolve_api_contract` span and the publish ingress conformance methods run inside
+//! `mesh.publish_ingress_conformance` spans. Each emits one event with the outcome: `debug` on success
+//! (carrying the resolved `service_name`), `warn` on failure (carrying `error_code` from
+//! `MeshRegistryError::code()` and the error message). Without the feature these wrappers compile away.
+//!
+//! ```ignore
+//! tracing_subscriber::fmt().with_max_level(tracing::Level::DEBUG).init();
+//!
+//! let registry = ServiceMeshRegistry::from_environment()?.expect("registry configured");
+//! // DEBUG mesh.resolve_api_contract{api_contract="worldbuilder.discovery.catalog.v1"}:
+//! //     api contract resolved service_name="backend-data-center"
+//! let catalog_target = registry.resolve_api_contract(API_DISCOVERY_CATALOG_V1)?;
+//! ```
+
+use crate::error::MeshRegistryError;
+use crate::models::ResolvedServiceTarget;
+
+#[cfg(feature = "tracing")]
+pub(crate) fn traced_resolution(
+    api_contract: &str,
+    resolve: impl FnOnce() -> Result<ResolvedServiceTarget, MeshRegistryError>,
+) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+    let _resolution_span = tracing::debug_span!("mesh.resolve_api_contract", api_contract = api_contract.trim()).entered();
+    let resolution = resolve();
+    match &resolution {
+        Ok(resolved_target) => tracing::debug!(service_name = resolved_target.service_name.as_str(), "api contract resolved"),
+        Err(resolution_error) => tracing::warn!(error_code = resolution_error.code(), error = %resolution_error, "api contract resolution failed"),
+    }
+    resolution
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn traced_resolution(
+    _api_contract: &str,
+    resolve: impl FnOnce() -> Result<ResolvedServiceTarget, MeshRegistryError>,
+) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+    resolve()
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn traced_publish_ingress_conformance(
+    check_name: &'static str,
+    hop_name: &str,
+    check: impl FnOnce() -> Result<(), MeshRegistryError>,
+) -> Result<(), MeshRegistryError> {
+    let _conformance_span = tracing::debug_span!("mesh.publish_ingress_conformance", check = check_name, hop_name = hop_name.trim()).entered();
+    let conformance = check();
+    match &conformance {
+        Ok(()) => tracing::debug!("publish ingress hop limits conform"),
+        Err(conformance_error) => {
+            tracing::warn!(error_code = conformance_error.code(), error = %conformance_error, "publish ingress hop limits do not conform")
+        }
+    }
+    conformance
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn traced_publish_ingress_conformance(
+    _check_name: &'static str,
+    _hop_name: &str,
+    check: impl FnOnce() -> Result<(), MeshRegistryError>,
+) -> Result<(), MeshRegistryError> {
+    check()
+}
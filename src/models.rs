@@ -1,4 +1,15 @@
-use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use url::Url;
+
+use crate::constants::{
+    DEFAULT_API_CONTRACT_CONTENT_TYPE, DEFAULT_HTTPS_REQUIRED_API_CONTRACT_PREFIXES, DEFAULT_MANDATORY_REJECTION_LOG_FIELDS, DEFAULT_MAX_REGISTRY_SERVICES,
+    DEFAULT_MAX_REGISTRY_SOURCE_BYTES, DEFAULT_MAX_REGISTRY_TOTAL_API_CONTRACTS, DEFAULT_SERVICE_ENDPOINT_WEIGHT,
+    PUBLISH_INGRESS_MAX_BODY_BYTES_SANITY_CEILING, UNIX_SOCKET_BASE_URL_SCHEME,
+};
+use crate::error::MeshRegistryError;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServiceMeshRegistryDocument {
@@ -6,6 +17,53 @@ pub struct ServiceMeshRegistryDocument {
     pub services: Vec<ServiceRegistration>,
     #[serde(default)]
     pub publish_ingress_policy: Option<PublishIngressPolicy>,
+    #[serde(default)]
+    pub api_contract_aliases: BTreeMap<String, String>,
+    #[serde(default)]
+    pub api_contract_dependencies: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub api_contract_routes: BTreeMap<String, ApiContractRoute>,
+    #[serde(default)]
+    pub api_contract_kinds: BTreeMap<String, ApiContractKind>,
+    #[serde(default)]
+    pub api_contract_enabled_when_env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub default_service: Option<String>,
+    #[serde(default)]
+    pub api_contract_content_types: BTreeMap<String, String>,
+    #[serde(default)]
+    pub api_contract_rate_limits: BTreeMap<String, RateLimit>,
+    #[serde(default)]
+    pub api_contract_requires_auth: BTreeMap<String, bool>,
+    #[serde(default)]
+    pub api_contract_environments: BTreeMap<String, Vec<String>>,
+}
+
+impl ServiceMeshRegistryDocument {
+    /// Runs the same checks as `ServiceMeshRegistry::from_document` without building a registry.
+    pub fn validate(&self) -> Result<(), MeshRegistryError> {
+        self.validate_with_options(&RegistryValidationOptions::default())
+    }
+
+    pub fn validate_with_options(
+        &self,
+        validation_options: &RegistryValidationOptions,
+    ) -> Result<(), MeshRegistryError> {
+        crate::validation::validate_registry_document(self, validation_options)
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl ServiceMeshRegistryDocument {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, MeshRegistryError> {
+        postcard::to_allocvec(self).map_err(|encode_error| MeshRegistryError::InvalidDocument(format!("failed to encode registry document: {}", encode_error)))
+    }
+
+    pub fn from_bytes(registry_bytes: &[u8]) -> Result<Self, MeshRegistryError> {
+        let document = postcard::from_bytes::<Self>(registry_bytes).map_err(|decode_error| MeshRegistryError::Decode(decode_error.to_string()))?;
+        document.validate()?;
+        Ok(document)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -13,13 +71,370 @@ pub struct ServiceRegistration {
     pub service_name: String,
     pub base_url: String,
     pub api_contracts: Vec<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub instances: Vec<ServiceInstance>,
+    #[serde(default = "default_service_endpoint_weight")]
+    pub weight: u32,
+    #[serde(default = "default_service_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub client_policy: Option<ClientPolicy>,
+    #[serde(default)]
+    pub health_path: Option<String>,
+    #[serde(default)]
+    pub environments: Vec<String>,
+    #[serde(default)]
+    pub failover_base_urls: Vec<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RateLimit {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientPolicy {
+    pub connect_timeout_ms: u64,
+    pub request_timeout_ms: u64,
+    pub max_retries: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ServiceInstance {
+    pub base_url: String,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(default = "default_service_endpoint_weight")]
+    pub weight: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiContractRoute {
+    pub method: String,
+    pub path: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiContractKind {
+    #[default]
+    Read,
+    Write,
+    Internal,
+}
+
+pub(crate) fn unix_socket_path(base_url: &str) -> Option<&str> {
+    let (scheme, socket_path) = base_url.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case(UNIX_SOCKET_BASE_URL_SCHEME) || !socket_path.starts_with('/') {
+        return None;
+    }
+    Some(socket_path)
+}
+
+fn default_service_endpoint_weight() -> u32 {
+    DEFAULT_SERVICE_ENDPOINT_WEIGHT
+}
+
+fn default_api_contract_content_type() -> String {
+    DEFAULT_API_CONTRACT_CONTENT_TYPE.to_string()
+}
+
+fn deserialize_one_or_many_api_contracts<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrManyApiContracts {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    if !deserializer.is_human_readable() {
+        return Vec::<String>::deserialize(deserializer);
+    }
+    Ok(match OneOrManyApiContracts::deserialize(deserializer)? {
+        OneOrManyApiContracts::One(api_contract) => vec![api_contract],
+        OneOrManyApiContracts::Many(api_contracts) => api_contracts,
+    })
+}
+
+fn default_service_enabled() -> bool {
+    true
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegistryValidationOptions {
+    pub strict: bool,
+    pub max_body_bytes_sanity_ceiling: u64,
+    /// Semantic limit checked after decoding, so it does not bound what serde allocates; `max_source_bytes` does.
+    pub max_services: usize,
+    /// Semantic limit checked after decoding, like `max_services`.
+    pub max_total_contracts: usize,
+    /// Raw payload cap for JSON/TOML strings, readers and files, enforced before or while decoding so oversized sources
+    /// fail without being parsed; already-decoded inputs (`from_document`, `from_value`) are not covered.
+    pub max_source_bytes: usize,
+    pub allow_unix_socket_targets: bool,
+    pub mandatory_rejection_log_fields: &'static [&'static str],
+    pub deny_unknown_fields: bool,
+    pub https_required_api_contract_prefixes: &'static [&'static str],
+    pub canonical_publish_ingress_hop_order: &'static [&'static str],
+    pub deny_unknown_api_contracts: bool,
+    /// When set, `policy_owner_product` must name a registered service or one of these products (warning, error in strict mode).
+    pub known_policy_owner_products: Option<&'static [&'static str]>,
+}
+
+impl RegistryValidationOptions {
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_canonical_publish_ingress_hop_order(
+        self,
+        canonical_publish_ingress_hop_order: &'static [&'static str],
+    ) -> Self {
+        Self {
+            canonical_publish_ingress_hop_order,
+            ..self
+        }
+    }
+
+    pub fn with_known_policy_owner_products(
+        self,
+        known_policy_owner_products: &'static [&'static str],
+    ) -> Self {
+        Self {
+            known_policy_owner_products: Some(known_policy_owner_products),
+            ..self
+        }
+    }
+}
+
+impl Default for RegistryValidationOptions {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            max_body_bytes_sanity_ceiling: PUBLISH_INGRESS_MAX_BODY_BYTES_SANITY_CEILING,
+            max_services: DEFAULT_MAX_REGISTRY_SERVICES,
+            max_total_contracts: DEFAULT_MAX_REGISTRY_TOTAL_API_CONTRACTS,
+            max_source_bytes: DEFAULT_MAX_REGISTRY_SOURCE_BYTES,
+            allow_unix_socket_targets: false,
+            mandatory_rejection_log_fields: &DEFAULT_MANDATORY_REJECTION_LOG_FIELDS,
+            deny_unknown_fields: false,
+            https_required_api_contract_prefixes: &DEFAULT_HTTPS_REQUIRED_API_CONTRACT_PREFIXES,
+            canonical_publish_ingress_hop_order: &[],
+            deny_unknown_api_contracts: false,
+            known_policy_owner_products: None,
+        }
+    }
+}
+
+/// Validation bundle selected by `WORLD_BUILDER_SERVICE_MESH_STRICTNESS` (`dev` when unset).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StrictnessProfile {
+    #[default]
+    Dev,
+    Staging,
+    Prod,
+}
+
+impl StrictnessProfile {
+    pub fn from_env_value(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "dev" => Some(StrictnessProfile::Dev),
+            "staging" => Some(StrictnessProfile::Staging),
+            "prod" => Some(StrictnessProfile::Prod),
+            _ => None,
+        }
+    }
+
+    pub fn validation_options(self) -> RegistryValidationOptions {
+        match self {
+            StrictnessProfile::Dev => RegistryValidationOptions::default(),
+            StrictnessProfile::Staging => RegistryValidationOptions {
+                deny_unknown_fields: true,
+                ..RegistryValidationOptions::default()
+            },
+            StrictnessProfile::Prod => RegistryValidationOptions {
+                deny_unknown_fields: true,
+                ..RegistryValidationOptions::strict()
+            },
+        }
+    }
+
+    pub fn requires_publish_ingress_policy(self) -> bool {
+        self == StrictnessProfile::Prod
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub errors: Vec<MeshRegistryError>,
+    pub warnings: Vec<MeshRegistryError>,
+}
+
+impl ValidationReport {
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ResolvedServiceTarget {
     pub service_name: String,
     pub base_url: String,
     pub api_contract: String,
+    #[serde(default)]
+    pub kind: ApiContractKind,
+    #[serde(default = "default_api_contract_content_type")]
+    pub content_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub requires_auth: bool,
+    /// Standby endpoints in preference order, tried in sequence only after `base_url` fails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failover_base_urls: Vec<String>,
+}
+
+impl ResolvedServiceTarget {
+    pub fn is_unix_socket(&self) -> bool {
+        self.unix_socket_path().is_some()
+    }
+
+    pub fn unix_socket_path(&self) -> Option<&str> {
+        unix_socket_path(&self.base_url)
+    }
+
+    /// Joins `path` onto `base_url` using RFC 3986 reference resolution (`Url::join`).
+    ///
+    /// A base path ending in `/` is kept as a prefix (`http://host/api/` + `catalog` -> `http://host/api/catalog`),
+    /// while a base path without a trailing slash drops its last segment (`http://host/api` + `catalog` -> `http://host/catalog`).
+    /// Paths starting with `/` replace the base path entirely.
+    ///
+    /// Unix socket targets have no URL base to join onto; callers dial `unix_socket_path()` and send `path` as-is.
+    pub fn join(
+        &self,
+        path: &str,
+    ) -> Result<Url, MeshRegistryError> {
+        if self.is_unix_socket() {
+            return Err(MeshRegistryError::InvalidTargetPath {
+                base_url: self.base_url.clone(),
+                path: path.to_string(),
+                message: "unix socket targets cannot be joined into a url".to_string(),
+            });
+        }
+        Url::parse(self.base_url.trim())
+            .and_then(|base_url| base_url.join(path))
+            .map_err(|parse_error| MeshRegistryError::InvalidTargetPath {
+                base_url: self.base_url.clone(),
+                path: path.to_string(),
+                message: parse_error.to_string(),
+            })
+    }
+
+    /// Joins `path` like `join` and converts the result into an `http::Uri`.
+    #[cfg(feature = "http")]
+    pub fn uri(
+        &self,
+        path: &str,
+    ) -> Result<http::Uri, MeshRegistryError> {
+        let joined_url = self.join(path)?;
+        http::Uri::try_from(joined_url.as_str()).map_err(|parse_error| MeshRegistryError::InvalidTargetPath {
+            base_url: self.base_url.clone(),
+            path: path.to_string(),
+            message: parse_error.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&ResolvedServiceTarget> for http::Uri {
+    type Error = MeshRegistryError;
+
+    fn try_from(resolved_target: &ResolvedServiceTarget) -> Result<Self, Self::Error> {
+        http::Uri::try_from(resolved_target.base_url.trim()).map_err(|parse_error| MeshRegistryError::InvalidTargetPath {
+            base_url: resolved_target.base_url.clone(),
+            path: String::new(),
+            message: parse_error.to_string(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResolvedServiceTargetRef<'a> {
+    pub service_name: &'a str,
+    pub base_url: &'a str,
+    pub api_contract: &'a str,
+    pub kind: ApiContractKind,
+    pub content_type: &'a str,
+    pub rate_limit: Option<RateLimit>,
+    pub requires_auth: bool,
+    pub failover_base_urls: &'a [String],
+}
+
+impl<'a> ResolvedServiceTargetRef<'a> {
+    pub fn is_unix_socket(&self) -> bool {
+        self.unix_socket_path().is_some()
+    }
+
+    pub fn unix_socket_path(&self) -> Option<&'a str> {
+        unix_socket_path(self.base_url)
+    }
+}
+
+impl From<ResolvedServiceTargetRef<'_>> for ResolvedServiceTarget {
+    fn from(resolved_target: ResolvedServiceTargetRef<'_>) -> Self {
+        Self {
+            service_name: resolved_target.service_name.to_string(),
+            base_url: resolved_target.base_url.to_string(),
+            api_contract: resolved_target.api_contract.to_string(),
+            kind: resolved_target.kind,
+            content_type: resolved_target.content_type.to_string(),
+            rate_limit: resolved_target.rate_limit,
+            requires_auth: resolved_target.requires_auth,
+            failover_base_urls: resolved_target.failover_base_urls.to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContractResolutionStats {
+    pub succeeded: u64,
+    pub failed: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RegistryMetrics {
+    pub service_count: usize,
+    pub contract_count: usize,
+    pub has_publish_policy: bool,
+    pub required_hop_count: usize,
+}
+
+/// Why `ServiceMeshRegistry::explain_resolution` expects a contract to resolve or fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResolutionExplanation {
+    Resolvable { api_contract: String, service_name: String },
+    ServiceDisabled { api_contract: String, service_name: String },
+    ContractDisabled { api_contract: String, env_var: String },
+    VersionRemoved { api_contract: String, latest_api_contract: String },
+    Misspelled { api_contract: String, suggestion: String },
+    Absent { api_contract: String },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -28,23 +443,88 @@ pub struct PublishIngressHopRuntimeLimit {
     pub configured_max_body_bytes: u64,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Orders by `configured_max_body_bytes`, then by `hop_name`.
+impl Ord for PublishIngressHopRuntimeLimit {
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering {
+        self.configured_max_body_bytes
+            .cmp(&other.configured_max_body_bytes)
+            .then_with(|| self.hop_name.cmp(&other.hop_name))
+    }
+}
+
+impl PartialOrd for PublishIngressHopRuntimeLimit {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegistryDiff {
+    pub services_added: Vec<String>,
+    pub services_removed: Vec<String>,
+    pub api_contracts_added: Vec<String>,
+    pub api_contracts_removed: Vec<String>,
+    pub base_url_changes: Vec<ServiceBaseUrlChange>,
+    pub api_contract_owner_changes: Vec<ApiContractOwnerChange>,
+    pub publish_ingress_policy_changed: bool,
+}
+
+impl RegistryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.services_added.is_empty()
+            && self.services_removed.is_empty()
+            && self.api_contracts_added.is_empty()
+            && self.api_contracts_removed.is_empty()
+            && self.base_url_changes.is_empty()
+            && self.api_contract_owner_changes.is_empty()
+            && !self.publish_ingress_policy_changed
+    }
+
+    pub fn has_removals(&self) -> bool {
+        !self.services_removed.is_empty() || !self.api_contracts_removed.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceBaseUrlChange {
+    pub service_name: String,
+    pub current_base_url: String,
+    pub proposed_base_url: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiContractOwnerChange {
+    pub api_contract: String,
+    pub current_service_name: String,
+    pub proposed_service_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PublishIngressPolicy {
     pub policy_owner_product: String,
-    pub publish_api_contract: String,
+    #[serde(alias = "publish_api_contract", deserialize_with = "deserialize_one_or_many_api_contracts")]
+    pub publish_api_contracts: Vec<String>,
     pub default_max_body_bytes: u64,
     pub required_hops: Vec<PublishIngressRequiredHop>,
     pub observability: PublishIngressObservability,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PublishIngressRequiredHop {
     pub hop_name: String,
     pub product: String,
     pub max_body_bytes_env_var: String,
+    #[serde(default)]
+    pub min_body_bytes: Option<u64>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PublishIngressObservability {
     pub rejection_metric_name: String,
     pub rejection_log_fields: Vec<String>,
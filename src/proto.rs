@@ -0,0 +1,471 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::constants::DEFAULT_SERVICE_ENDPOINT_WEIGHT;
+use crate::error::MeshRegistryError;
+use crate::models;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ServiceMeshRegistryDocument {
+    #[prost(string, tag = "1")]
+    pub version: String,
+    #[prost(message, repeated, tag = "2")]
+    pub services: Vec<ServiceRegistration>,
+    #[prost(message, optional, tag = "3")]
+    pub publish_ingress_policy: Option<PublishIngressPolicy>,
+    #[prost(btree_map = "string, string", tag = "4")]
+    pub api_contract_aliases: BTreeMap<String, String>,
+    #[prost(btree_map = "string, message", tag = "5")]
+    pub api_contract_dependencies: BTreeMap<String, ApiContractDependencies>,
+    #[prost(btree_map = "string, message", tag = "6")]
+    pub api_contract_routes: BTreeMap<String, ApiContractRoute>,
+    #[prost(btree_map = "string, enumeration(ApiContractKind)", tag = "7")]
+    pub api_contract_kinds: BTreeMap<String, i32>,
+    #[prost(string, optional, tag = "8")]
+    pub default_service: Option<String>,
+    #[prost(btree_map = "string, string", tag = "9")]
+    pub api_contract_enabled_when_env: BTreeMap<String, String>,
+    #[prost(btree_map = "string, string", tag = "10")]
+    pub api_contract_content_types: BTreeMap<String, String>,
+    #[prost(btree_map = "string, message", tag = "11")]
+    pub api_contract_rate_limits: BTreeMap<String, RateLimit>,
+    #[prost(btree_map = "string, bool", tag = "12")]
+    pub api_contract_requires_auth: BTreeMap<String, bool>,
+    #[prost(btree_map = "string, message", tag = "13")]
+    pub api_contract_environments: BTreeMap<String, ApiContractEnvironments>,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+pub struct RateLimit {
+    #[prost(uint32, tag = "1")]
+    pub requests_per_minute: u32,
+    #[prost(uint32, tag = "2")]
+    pub burst: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum ApiContractKind {
+    Read = 0,
+    Write = 1,
+    Internal = 2,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ApiContractDependencies {
+    #[prost(string, repeated, tag = "1")]
+    pub depends_on: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ApiContractEnvironments {
+    #[prost(string, repeated, tag = "1")]
+    pub environments: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ApiContractRoute {
+    #[prost(string, tag = "1")]
+    pub method: String,
+    #[prost(string, tag = "2")]
+    pub path: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ServiceRegistration {
+    #[prost(string, tag = "1")]
+    pub service_name: String,
+    #[prost(string, tag = "2")]
+    pub base_url: String,
+    #[prost(string, repeated, tag = "3")]
+    pub api_contracts: Vec<String>,
+    #[prost(map = "string, string", tag = "4")]
+    pub metadata: HashMap<String, String>,
+    #[prost(message, repeated, tag = "5")]
+    pub instances: Vec<ServiceInstance>,
+    #[prost(uint32, optional, tag = "6")]
+    pub weight: Option<u32>,
+    #[prost(bool, optional, tag = "7")]
+    pub enabled: Option<bool>,
+    #[prost(message, optional, tag = "8")]
+    pub client_policy: Option<ClientPolicy>,
+    #[prost(string, optional, tag = "9")]
+    pub health_path: Option<String>,
+    #[prost(string, repeated, tag = "10")]
+    pub environments: Vec<String>,
+    #[prost(string, repeated, tag = "11")]
+    pub failover_base_urls: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientPolicy {
+    #[prost(uint64, tag = "1")]
+    pub connect_timeout_ms: u64,
+    #[prost(uint64, tag = "2")]
+    pub request_timeout_ms: u64,
+    #[prost(uint32, tag = "3")]
+    pub max_retries: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ServiceInstance {
+    #[prost(string, tag = "1")]
+    pub base_url: String,
+    #[prost(map = "string, string", tag = "2")]
+    pub metadata: HashMap<String, String>,
+    #[prost(uint32, optional, tag = "3")]
+    pub weight: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublishIngressPolicy {
+    #[prost(string, tag = "1")]
+    pub policy_owner_product: String,
+    #[prost(string, repeated, tag = "2")]
+    pub publish_api_contracts: Vec<String>,
+    #[prost(uint64, tag = "3")]
+    pub default_max_body_bytes: u64,
+    #[prost(message, repeated, tag = "4")]
+    pub required_hops: Vec<PublishIngressRequiredHop>,
+    #[prost(message, optional, tag = "5")]
+    pub observability: Option<PublishIngressObservability>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublishIngressRequiredHop {
+    #[prost(string, tag = "1")]
+    pub hop_name: String,
+    #[prost(string, tag = "2")]
+    pub product: String,
+    #[prost(string, tag = "3")]
+    pub max_body_bytes_env_var: String,
+    #[prost(uint64, optional, tag = "4")]
+    pub min_body_bytes: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublishIngressObservability {
+    #[prost(string, tag = "1")]
+    pub rejection_metric_name: String,
+    #[prost(string, repeated, tag = "2")]
+    pub rejection_log_fields: Vec<String>,
+}
+
+pub(crate) fn decode_registry_document(registry_bytes: &[u8]) -> Result<models::ServiceMeshRegistryDocument, MeshRegistryError> {
+    let document =
+        <ServiceMeshRegistryDocument as prost::Message>::decode(registry_bytes).map_err(|decode_error| MeshRegistryError::Decode(decode_error.to_string()))?;
+    models::ServiceMeshRegistryDocument::try_from(document)
+}
+
+impl From<models::ServiceMeshRegistryDocument> for ServiceMeshRegistryDocument {
+    fn from(document: models::ServiceMeshRegistryDocument) -> Self {
+        Self {
+            version: document.version,
+            services: document
+                .services
+                .into_iter()
+                .map(ServiceRegistration::from)
+                .collect(),
+            publish_ingress_policy: document.publish_ingress_policy.map(PublishIngressPolicy::from),
+            api_contract_aliases: document.api_contract_aliases,
+            api_contract_dependencies: document
+                .api_contract_dependencies
+                .into_iter()
+                .map(|(api_contract, depends_on)| (api_contract, ApiContractDependencies { depends_on }))
+                .collect(),
+            api_contract_routes: document
+                .api_contract_routes
+                .into_iter()
+                .map(|(api_contract, route)| (api_contract, ApiContractRoute::from(route)))
+                .collect(),
+            api_contract_kinds: document
+                .api_contract_kinds
+                .into_iter()
+                .map(|(api_contract, kind)| (api_contract, ApiContractKind::from(kind) as i32))
+                .collect(),
+            default_service: document.default_service,
+            api_contract_enabled_when_env: document.api_contract_enabled_when_env,
+            api_contract_content_types: document.api_contract_content_types,
+            api_contract_rate_limits: document
+                .api_contract_rate_limits
+                .into_iter()
+                .map(|(api_contract, rate_limit)| (api_contract, RateLimit::from(rate_limit)))
+                .collect(),
+            api_contract_requires_auth: document.api_contract_requires_auth,
+            api_contract_environments: document
+                .api_contract_environments
+                .into_iter()
+                .map(|(api_contract, environments)| (api_contract, ApiContractEnvironments { environments }))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<ServiceMeshRegistryDocument> for models::ServiceMeshRegistryDocument {
+    type Error = MeshRegistryError;
+
+    fn try_from(document: ServiceMeshRegistryDocument) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: document.version,
+            services: document
+                .services
+                .into_iter()
+                .map(models::ServiceRegistration::from)
+                .collect(),
+            publish_ingress_policy: document
+                .publish_ingress_policy
+                .map(models::PublishIngressPolicy::try_from)
+                .transpose()?,
+            api_contract_aliases: document.api_contract_aliases,
+            api_contract_dependencies: document
+                .api_contract_dependencies
+                .into_iter()
+                .map(|(api_contract, dependencies)| (api_contract, dependencies.depends_on))
+                .collect(),
+            api_contract_routes: document
+                .api_contract_routes
+                .into_iter()
+                .map(|(api_contract, route)| (api_contract, models::ApiContractRoute::from(route)))
+                .collect(),
+            api_contract_kinds: document
+                .api_contract_kinds
+                .into_iter()
+                .map(|(api_contract, kind)| {
+                    let kind = ApiContractKind::try_from(kind)
+                        .map_err(|_| MeshRegistryError::Decode(format!("api contract '{}' has unknown kind {}", api_contract, kind)))?;
+                    Ok((api_contract, models::ApiContractKind::from(kind)))
+                })
+                .collect::<Result<_, MeshRegistryError>>()?,
+            default_service: document.default_service,
+            api_contract_enabled_when_env: document.api_contract_enabled_when_env,
+            api_contract_content_types: document.api_contract_content_types,
+            api_contract_rate_limits: document
+                .api_contract_rate_limits
+                .into_iter()
+                .map(|(api_contract, rate_limit)| (api_contract, models::RateLimit::from(rate_limit)))
+                .collect(),
+            api_contract_requires_auth: document.api_contract_requires_auth,
+            api_contract_environments: document
+                .api_contract_environments
+                .into_iter()
+                .map(|(api_contract, environments)| (api_contract, environments.environments))
+                .collect(),
+        })
+    }
+}
+
+impl From<models::ApiContractRoute> for ApiContractRoute {
+    fn from(route: models::ApiContractRoute) -> Self {
+        Self {
+            method: route.method,
+            path: route.path,
+        }
+    }
+}
+
+impl From<ApiContractRoute> for models::ApiContractRoute {
+    fn from(route: ApiContractRoute) -> Self {
+        Self {
+            method: route.method,
+            path: route.path,
+        }
+    }
+}
+
+impl From<models::ApiContractKind> for ApiContractKind {
+    fn from(kind: models::ApiContractKind) -> Self {
+        match kind {
+            models::ApiContractKind::Read => ApiContractKind::Read,
+            models::ApiContractKind::Write => ApiContractKind::Write,
+            models::ApiContractKind::Internal => ApiContractKind::Internal,
+        }
+    }
+}
+
+impl From<ApiContractKind> for models::ApiContractKind {
+    fn from(kind: ApiContractKind) -> Self {
+        match kind {
+            ApiContractKind::Read => models::ApiContractKind::Read,
+            ApiContractKind::Write => models::ApiContractKind::Write,
+            ApiContractKind::Internal => models::ApiContractKind::Internal,
+        }
+    }
+}
+
+impl From<models::ServiceRegistration> for ServiceRegistration {
+    fn from(service: models::ServiceRegistration) -> Self {
+        Self {
+            service_name: service.service_name,
+            base_url: service.base_url,
+            api_contracts: service.api_contracts,
+            metadata: service.metadata,
+            instances: service
+                .instances
+                .into_iter()
+                .map(ServiceInstance::from)
+                .collect(),
+            weight: Some(service.weight),
+            enabled: Some(service.enabled),
+            client_policy: service.client_policy.map(ClientPolicy::from),
+            health_path: service.health_path,
+            environments: service.environments,
+            failover_base_urls: service.failover_base_urls,
+        }
+    }
+}
+
+impl From<ServiceRegistration> for models::ServiceRegistration {
+    fn from(service: ServiceRegistration) -> Self {
+        Self {
+            service_name: service.service_name,
+            base_url: service.base_url,
+            api_contracts: service.api_contracts,
+            metadata: service.metadata,
+            instances: service
+                .instances
+                .into_iter()
+                .map(models::ServiceInstance::from)
+                .collect(),
+            weight: service.weight.unwrap_or(DEFAULT_SERVICE_ENDPOINT_WEIGHT),
+            enabled: service.enabled.unwrap_or(true),
+            client_policy: service.client_policy.map(models::ClientPolicy::from),
+            health_path: service.health_path,
+            environments: service.environments,
+            failover_base_urls: service.failover_base_urls,
+        }
+    }
+}
+
+impl From<models::RateLimit> for RateLimit {
+    fn from(rate_limit: models::RateLimit) -> Self {
+        Self {
+            requests_per_minute: rate_limit.requests_per_minute,
+            burst: rate_limit.burst,
+        }
+    }
+}
+
+impl From<RateLimit> for models::RateLimit {
+    fn from(rate_limit: RateLimit) -> Self {
+        Self {
+            requests_per_minute: rate_limit.requests_per_minute,
+            burst: rate_limit.burst,
+        }
+    }
+}
+
+impl From<models::ClientPolicy> for ClientPolicy {
+    fn from(client_policy: models::ClientPolicy) -> Self {
+        Self {
+            connect_timeout_ms: client_policy.connect_timeout_ms,
+            request_timeout_ms: client_policy.request_timeout_ms,
+            max_retries: client_policy.max_retries,
+        }
+    }
+}
+
+impl From<ClientPolicy> for models::ClientPolicy {
+    fn from(client_policy: ClientPolicy) -> Self {
+        Self {
+            connect_timeout_ms: client_policy.connect_timeout_ms,
+            request_timeout_ms: client_policy.request_timeout_ms,
+            max_retries: client_policy.max_retries,
+        }
+    }
+}
+
+impl From<models::ServiceInstance> for ServiceInstance {
+    fn from(instance: models::ServiceInstance) -> Self {
+        Self {
+            base_url: instance.base_url,
+            metadata: instance.metadata,
+            weight: Some(instance.weight),
+        }
+    }
+}
+
+impl From<ServiceInstance> for models::ServiceInstance {
+    fn from(instance: ServiceInstance) -> Self {
+        Self {
+            base_url: instance.base_url,
+            metadata: instance.metadata,
+            weight: instance.weight.unwrap_or(DEFAULT_SERVICE_ENDPOINT_WEIGHT),
+        }
+    }
+}
+
+impl From<models::PublishIngressPolicy> for PublishIngressPolicy {
+    fn from(publish_ingress_policy: models::PublishIngressPolicy) -> Self {
+        Self {
+            policy_owner_product: publish_ingress_policy.policy_owner_product,
+            publish_api_contracts: publish_ingress_policy.publish_api_contracts,
+            default_max_body_bytes: publish_ingress_policy.default_max_body_bytes,
+            required_hops: publish_ingress_policy
+                .required_hops
+                .into_iter()
+                .map(PublishIngressRequiredHop::from)
+                .collect(),
+            observability: Some(PublishIngressObservability::from(publish_ingress_policy.observability)),
+        }
+    }
+}
+
+impl TryFrom<PublishIngressPolicy> for models::PublishIngressPolicy {
+    type Error = MeshRegistryError;
+
+    fn try_from(publish_ingress_policy: PublishIngressPolicy) -> Result<Self, Self::Error> {
+        let observability = publish_ingress_policy
+            .observability
+            .ok_or_else(|| MeshRegistryError::Decode("publish_ingress_policy.observability is required".to_string()))?;
+        Ok(Self {
+            policy_owner_product: publish_ingress_policy.policy_owner_product,
+            publish_api_contracts: publish_ingress_policy.publish_api_contracts,
+            default_max_body_bytes: publish_ingress_policy.default_max_body_bytes,
+            required_hops: publish_ingress_policy
+                .required_hops
+                .into_iter()
+                .map(models::PublishIngressRequiredHop::from)
+                .collect(),
+            observability: models::PublishIngressObservability::from(observability),
+        })
+    }
+}
+
+impl From<models::PublishIngressRequiredHop> for PublishIngressRequiredHop {
+    fn from(required_hop: models::PublishIngressRequiredHop) -> Self {
+        Self {
+            hop_name: required_hop.hop_name,
+            product: required_hop.product,
+            max_body_bytes_env_var: required_hop.max_body_bytes_env_var,
+            min_body_bytes: required_hop.min_body_bytes,
+        }
+    }
+}
+
+impl From<PublishIngressRequiredHop> for models::PublishIngressRequiredHop {
+    fn from(required_hop: PublishIngressRequiredHop) -> Self {
+        Self {
+            hop_name: required_hop.hop_name,
+            product: required_hop.product,
+            max_body_bytes_env_var: required_hop.max_body_bytes_env_var,
+            min_body_bytes: required_hop.min_body_bytes,
+        }
+    }
+}
+
+impl From<models::PublishIngressObservability> for PublishIngressObservability {
+    fn from(observability: models::PublishIngressObservability) -> Self {
+        Self {
+            rejection_metric_name: observability.rejection_metric_name,
+            rejection_log_fields: observability.rejection_log_fields,
+        }
+    }
+}
+
+impl From<PublishIngressObservability> for models::PublishIngressObservability {
+    fn from(observability: PublishIngressObservability) -> Self {
+        Self {
+            rejection_metric_name: observability.rejection_metric_name,
+            rejection_log_fields: observability.rejection_log_fields,
+        }
+    }
+}
@@ -1,50 +1,377 @@
-use std::collections::HashMap;
-use std::env;
-use std::fs;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 
-use crate::constants::{ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::api_contract_index::{ApiContractIndex, ApiContractIndexKind};
+use crate::byte_size::parse_byte_size;
+use crate::constants::{
+    DEFAULT_API_CONTRACT_CONTENT_TYPE, DEFAULT_SERVICE_ENDPOINT_WEIGHT, DEFAULT_SERVICE_HEALTH_PATH, ENV_WORLD_BUILDER_ENV,
+    ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH, ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY,
+    ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS, SERVICE_METADATA_REGION_KEY,
+};
+use crate::contract_dependencies::find_api_contract_dependency_cycle;
+use crate::contract_suggestion::closest_api_contract;
+use crate::contract_version::parse_api_contract_version;
+use crate::diff::{diff_registry_services, render_registry_changelog};
+use crate::env_source::{EnvSource, PrefixedEnv, ProcessEnv, env_flag_enabled};
 use crate::error::MeshRegistryError;
+use crate::mesh_tracing::{traced_publish_ingress_conformance, traced_resolution};
 use crate::models::{
-    PublishIngressHopRuntimeLimit, PublishIngressPolicy, PublishIngressRequiredHop, ResolvedServiceTarget, ServiceMeshRegistryDocument, ServiceRegistration,
+    ApiContractKind, ApiContractRoute, ClientPolicy, PublishIngressHopRuntimeLimit, PublishIngressPolicy, PublishIngressRequiredHop, RateLimit, RegistryDiff,
+    RegistryMetrics, RegistryValidationOptions, ResolutionExplanation, ResolvedServiceTarget, ResolvedServiceTargetRef, ServiceMeshRegistryDocument,
+    ServiceRegistration, StrictnessProfile, unix_socket_path,
 };
-use crate::validation::validate_registry_document;
+use crate::registry_directory::assemble_registry_directory;
+use crate::route_table::RouteTable;
+use crate::source_size_limit::{SourceSizeLimitedReader, ensure_source_within_limit, source_size_limit_error};
+use crate::unknown_fields::ensure_no_unknown_fields;
+use crate::validation::{validate_base_url, validate_registry_document};
 
 #[derive(Clone, Debug)]
 pub struct ServiceMeshRegistry {
+    state: Arc<ServiceMeshRegistryState>,
+}
+
+#[derive(Debug)]
+struct ServiceMeshRegistryState {
     version: String,
+    balanced_selection_counters: Vec<AtomicU64>,
+    validation_options: RegistryValidationOptions,
     services: Vec<ServiceRegistration>,
     publish_ingress_policy: Option<PublishIngressPolicy>,
-    api_contract_to_service_index: HashMap<String, usize>,
+    service_name_to_index: HashMap<String, usize>,
+    parsed_base_urls: Vec<Option<Url>>,
+    api_contract_to_service_index: ApiContractIndex,
+    api_contract_aliases: HashMap<String, String>,
+    api_contract_dependencies: BTreeMap<String, Vec<String>>,
+    api_contract_routes: BTreeMap<String, ApiContractRoute>,
+    api_contract_kinds: BTreeMap<String, ApiContractKind>,
+    api_contract_content_types: BTreeMap<String, String>,
+    api_contract_rate_limits: BTreeMap<String, RateLimit>,
+    api_contract_requires_auth: BTreeMap<String, bool>,
+    api_contract_environments: BTreeMap<String, Vec<String>>,
+    route_table: RouteTable,
+    api_contract_enabled_when_env: BTreeMap<String, String>,
+    enabled_gated_api_contracts: BTreeSet<String>,
+    default_service: Option<String>,
+    default_service_index: Option<usize>,
+}
+
+const UTF8_BYTE_ORDER_MARK: &[u8] = "\u{feff}".as_bytes();
+
+pub(crate) fn strip_registry_source_preamble(registry_source: &str) -> &str {
+    registry_source.trim_start_matches('\u{feff}').trim()
+}
+
+pub(crate) fn decode_json_document(registry_json: &str) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    let document = serde_json::from_str::<ServiceMeshRegistryDocument>(strip_registry_source_preamble(registry_json))?;
+    Ok(document)
+}
+
+pub(crate) fn decode_json_document_with_options(
+    registry_json: &str,
+    validation_options: &RegistryValidationOptions,
+) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    ensure_source_within_limit(registry_json.len(), validation_options.max_source_bytes)?;
+    if !validation_options.deny_unknown_fields {
+        return decode_json_document(registry_json);
+    }
+    let source_value = serde_json::from_str::<serde_json::Value>(strip_registry_source_preamble(registry_json))?;
+    decode_value_document_with_options(source_value, validation_options)
+}
+
+fn decode_json_reader_with_options(
+    reader: impl Read,
+    validation_options: &RegistryValidationOptions,
+) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    let mut reader = BufReader::new(SourceSizeLimitedReader::new(reader, validation_options.max_source_bytes));
+    let size_limited_json_reader_error = |reader: &BufReader<SourceSizeLimitedReader<_>>, reader_error| {
+        if reader.get_ref().exceeded_limit() {
+            return source_size_limit_error(validation_options.max_source_bytes);
+        }
+        json_reader_error(reader_error)
+    };
+    if reader.fill_buf()?.starts_with(UTF8_BYTE_ORDER_MARK) {
+        reader.consume(UTF8_BYTE_ORDER_MARK.len());
+    }
+    if !validation_options.deny_unknown_fields {
+        return serde_json::from_reader::<_, ServiceMeshRegistryDocument>(&mut reader)
+            .map_err(|reader_error| size_limited_json_reader_error(&reader, reader_error));
+    }
+    let source_value =
+        serde_json::from_reader::<_, serde_json::Value>(&mut reader).map_err(|reader_error| size_limited_json_reader_error(&reader, reader_error))?;
+    decode_value_document_with_options(source_value, validation_options)
+}
+
+fn decode_file_document_with_options(
+    registry_path: &Path,
+    validation_options: &RegistryValidationOptions,
+) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    let is_toml_document = registry_path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+    if is_toml_document {
+        let mut registry_reader = SourceSizeLimitedReader::new(File::open(registry_path)?, validation_options.max_source_bytes);
+        let mut registry_source = String::new();
+        if let Err(io_error) = registry_reader.read_to_string(&mut registry_source) {
+            if registry_reader.exceeded_limit() {
+                return Err(source_size_limit_error(validation_options.max_source_bytes));
+            }
+            return Err(io_error.into());
+        }
+        return decode_toml_document_with_options(strip_registry_source_preamble(&registry_source), validation_options);
+    }
+    decode_json_reader_with_options(File::open(registry_path)?, validation_options)
+}
+
+#[cfg(feature = "toml")]
+fn decode_toml_document(registry_source: &str) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    toml::from_str::<ServiceMeshRegistryDocument>(registry_source).map_err(|decode_error| MeshRegistryError::Decode(decode_error.to_string()))
+}
+
+/// Checks unknown fields like JSON sources do, by routing the parsed TOML through `serde_json::Value`.
+#[cfg(feature = "toml")]
+fn decode_toml_document_with_options(
+    registry_source: &str,
+    validation_options: &RegistryValidationOptions,
+) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    if !validation_options.deny_unknown_fields {
+        return decode_toml_document(registry_source);
+    }
+    let toml_value = toml::from_str::<toml::Value>(registry_source).map_err(|decode_error| MeshRegistryError::Decode(decode_error.to_string()))?;
+    decode_value_document_with_options(serde_json::to_value(toml_value)?, validation_options)
+}
+
+#[cfg(not(feature = "toml"))]
+fn decode_toml_document_with_options(
+    registry_source: &str,
+    _validation_options: &RegistryValidationOptions,
+) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    decode_toml_document(registry_source)
+}
+
+#[cfg(not(feature = "toml"))]
+fn decode_toml_document(_registry_source: &str) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    Err(MeshRegistryError::Decode("toml registry documents require the 'toml' feature".to_string()))
+}
+
+fn json_reader_error(reader_error: serde_json::Error) -> MeshRegistryError {
+    if reader_error.is_io() {
+        return MeshRegistryError::Io(reader_error.to_string());
+    }
+    MeshRegistryError::from(reader_error)
+}
+
+fn decode_value_document_with_options(
+    source_value: serde_json::Value,
+    validation_options: &RegistryValidationOptions,
+) -> Result<ServiceMeshRegistryDocument, MeshRegistryError> {
+    if !validation_options.deny_unknown_fields {
+        return Ok(serde_json::from_value::<ServiceMeshRegistryDocument>(source_value)?);
+    }
+    let document = ServiceMeshRegistryDocument::deserialize(&source_value)?;
+    ensure_no_unknown_fields(&source_value, &document)?;
+    Ok(document)
 }
 
 impl ServiceMeshRegistry {
+    /// Reads `api_contract_enabled_when_env` flags from the process environment, as do the `from_json_str`,
+    /// `from_value`, `from_file_path`, `from_reader`, `from_directory`, `from_toml_str` and `from_proto_bytes` families
+    /// built on it. Use `ServiceMeshRegistryBuilder::env_source` to read them from another `EnvSource`.
     pub fn from_document(document: ServiceMeshRegistryDocument) -> Result<Self, MeshRegistryError> {
-        validate_registry_document(&document)?;
+        Self::from_document_with_options(document, &RegistryValidationOptions::default())
+    }
+
+    pub fn from_document_with_options(
+        document: ServiceMeshRegistryDocument,
+        validation_options: &RegistryValidationOptions,
+    ) -> Result<Self, MeshRegistryError> {
+        Self::from_document_with_env(document, validation_options, &ProcessEnv, ApiContractIndexKind::Hashed)
+    }
+
+    /// Builds the registry, reading `api_contract_enabled_when_env` flags once from `env_source`.
+    pub(crate) fn from_document_with_env(
+        document: ServiceMeshRegistryDocument,
+        validation_options: &RegistryValidationOptions,
+        env_source: &impl EnvSource,
+        api_contract_index_kind: ApiContractIndexKind,
+    ) -> Result<Self, MeshRegistryError> {
+        validate_registry_document(&document, validation_options)?;
+        let mut service_name_to_index = HashMap::<String, usize>::new();
         let mut api_contract_to_service_index = HashMap::<String, usize>::new();
         for (service_index, service) in document.services.iter().enumerate() {
+            service_name_to_index.insert(service.service_name.trim().to_string(), service_index);
             for api_contract in &service.api_contracts {
                 api_contract_to_service_index.insert(api_contract.clone(), service_index);
             }
         }
+        let api_contract_aliases = document
+            .api_contract_aliases
+            .iter()
+            .map(|(alias, canonical_api_contract)| (alias.trim().to_string(), canonical_api_contract.trim().to_string()))
+            .collect();
+        let api_contract_dependencies = document
+            .api_contract_dependencies
+            .iter()
+            .map(|(api_contract, dependencies)| {
+                (
+                    api_contract.trim().to_string(),
+                    dependencies
+                        .iter()
+                        .map(|dependency| dependency.trim().to_string())
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let api_contract_kinds = document
+            .api_contract_kinds
+            .iter()
+            .map(|(api_contract, kind)| (api_contract.trim().to_string(), *kind))
+            .collect();
+        let api_contract_content_types = document
+            .api_contract_content_types
+            .iter()
+            .map(|(api_contract, content_type)| (api_contract.trim().to_string(), content_type.trim().to_string()))
+            .collect();
+        let api_contract_rate_limits = document
+            .api_contract_rate_limits
+            .iter()
+            .map(|(api_contract, rate_limit)| (api_contract.trim().to_string(), *rate_limit))
+            .collect();
+        let api_contract_requires_auth = document
+            .api_contract_requires_auth
+            .iter()
+            .map(|(api_contract, requires_auth)| (api_contract.trim().to_string(), *requires_auth))
+            .collect();
+        let api_contract_environments = document
+            .api_contract_environments
+            .iter()
+            .map(|(api_contract, environments)| (api_contract.trim().to_string(), environments.clone()))
+            .collect();
+        let default_service_index = document
+            .default_service
+            .as_ref()
+            .and_then(|default_service| service_name_to_index.get(default_service.trim()).copied());
+        let parsed_base_urls = document
+            .services
+            .iter()
+            .map(|service| Url::parse(service.base_url.trim()).ok())
+            .collect();
+        let api_contract_enabled_when_env = document
+            .api_contract_enabled_when_env
+            .iter()
+            .map(|(api_contract, enabled_when_env)| (api_contract.trim().to_string(), enabled_when_env.trim().to_string()))
+            .collect::<BTreeMap<_, _>>();
+        let enabled_gated_api_contracts = api_contract_enabled_when_env
+            .iter()
+            .filter(|(_, enabled_when_env)| env_flag_enabled(env_source, enabled_when_env))
+            .map(|(api_contract, _)| api_contract.clone())
+            .collect();
+        let route_table = RouteTable::from_api_contract_routes(&document.api_contract_routes);
 
         Ok(Self {
-            version: document.version,
-            services: document.services,
-            publish_ingress_policy: document.publish_ingress_policy,
-            api_contract_to_service_index,
+            state: Arc::new(ServiceMeshRegistryState {
+                version: document.version,
+                balanced_selection_counters: document.services.iter().map(|_| AtomicU64::new(0)).collect(),
+                validation_options: *validation_options,
+                services: document.services,
+                publish_ingress_policy: document.publish_ingress_policy,
+                service_name_to_index,
+                parsed_base_urls,
+                api_contract_to_service_index: ApiContractIndex::build(api_contract_to_service_index, api_contract_index_kind),
+                api_contract_aliases,
+                api_contract_dependencies,
+                api_contract_routes: document.api_contract_routes,
+                api_contract_kinds,
+                api_contract_content_types,
+                api_contract_rate_limits,
+                api_contract_requires_auth,
+                api_contract_environments,
+                route_table,
+                api_contract_enabled_when_env,
+                enabled_gated_api_contracts,
+                default_service: document.default_service,
+                default_service_index,
+            }),
         })
     }
 
     pub fn from_json_str(registry_json: &str) -> Result<Self, MeshRegistryError> {
-        let document =
-            serde_json::from_str::<ServiceMeshRegistryDocument>(registry_json).map_err(|decode_error| MeshRegistryError::Decode(decode_error.to_string()))?;
-        Self::from_document(document)
+        Self::from_json_str_with_options(registry_json, &RegistryValidationOptions::default())
+    }
+
+    pub fn from_json_str_with_options(
+        registry_json: &str,
+        validation_options: &RegistryValidationOptions,
+    ) -> Result<Self, MeshRegistryError> {
+        Self::from_document_with_options(decode_json_document_with_options(registry_json, validation_options)?, validation_options)
+    }
+
+    pub fn from_value(registry_value: serde_json::Value) -> Result<Self, MeshRegistryError> {
+        Self::from_value_with_options(registry_value, &RegistryValidationOptions::default())
+    }
+
+    pub fn from_value_with_options(
+        registry_value: serde_json::Value,
+        validation_options: &RegistryValidationOptions,
+    ) -> Result<Self, MeshRegistryError> {
+        Self::from_document_with_options(decode_value_document_with_options(registry_value, validation_options)?, validation_options)
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn from_proto_bytes(registry_bytes: &[u8]) -> Result<Self, MeshRegistryError> {
+        Self::from_document(crate::proto::decode_registry_document(registry_bytes)?)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(registry_toml: &str) -> Result<Self, MeshRegistryError> {
+        Self::from_document(decode_toml_document(registry_toml)?)
     }
 
     pub fn from_file_path(registry_path: impl AsRef<Path>) -> Result<Self, MeshRegistryError> {
-        let registry_source = fs::read_to_string(registry_path.as_ref()).map_err(|io_error| MeshRegistryError::Io(io_error.to_string()))?;
-        Self::from_json_str(&registry_source)
+        Self::from_file_path_with_options(registry_path, &RegistryValidationOptions::default())
+    }
+
+    pub fn from_file_path_with_options(
+        registry_path: impl AsRef<Path>,
+        validation_options: &RegistryValidationOptions,
+    ) -> Result<Self, MeshRegistryError> {
+        Self::from_document_with_options(
+            decode_file_document_with_options(registry_path.as_ref(), validation_options)?,
+            validation_options,
+        )
+    }
+
+    /// Stream-parses a JSON registry document from `reader` instead of buffering the whole source first.
+    pub fn from_reader(reader: impl Read) -> Result<Self, MeshRegistryError> {
+        Self::from_reader_with_options(reader, &RegistryValidationOptions::default())
+    }
+
+    pub fn from_reader_with_options(
+        reader: impl Read,
+        validation_options: &RegistryValidationOptions,
+    ) -> Result<Self, MeshRegistryError> {
+        Self::from_document_with_options(decode_json_reader_with_options(reader, validation_options)?, validation_options)
+    }
+
+    pub fn from_directory(registry_dir: impl AsRef<Path>) -> Result<Self, MeshRegistryError> {
+        Self::from_value(assemble_registry_directory(registry_dir.as_ref(), None)?)
+    }
+
+    pub fn from_directory_with_version(
+        registry_dir: impl AsRef<Path>,
+        version: &str,
+    ) -> Result<Self, MeshRegistryError> {
+        Self::from_value(assemble_registry_directory(registry_dir.as_ref(), Some(version))?)
     }
 
     pub fn single_service(
@@ -59,26 +386,100 @@ impl ServiceMeshRegistry {
                 service_name: service_name.into(),
                 base_url: base_url.into(),
                 api_contracts: api_contracts.into_iter().map(Into::into).collect(),
+                metadata: HashMap::new(),
+                instances: Vec::new(),
+                weight: DEFAULT_SERVICE_ENDPOINT_WEIGHT,
+                enabled: true,
+                client_policy: None,
+                health_path: None,
+                environments: Vec::new(),
+                failover_base_urls: Vec::new(),
             }],
             publish_ingress_policy: None,
+            api_contract_aliases: BTreeMap::new(),
+            api_contract_dependencies: BTreeMap::new(),
+            api_contract_routes: BTreeMap::new(),
+            api_contract_kinds: BTreeMap::new(),
+            api_contract_content_types: BTreeMap::new(),
+            api_contract_rate_limits: BTreeMap::new(),
+            api_contract_requires_auth: BTreeMap::new(),
+            api_contract_environments: BTreeMap::new(),
+            api_contract_enabled_when_env: BTreeMap::new(),
+            default_service: None,
         };
         Self::from_document(document)
     }
 
+    pub fn with_additional_contract(
+        self,
+        service_name: &str,
+        api_contract: &str,
+    ) -> Result<Self, MeshRegistryError> {
+        let normalized_service_name = service_name.trim();
+        let mut document = self.to_document();
+        let Some(service) = document
+            .services
+            .iter_mut()
+            .find(|service| service.service_name.trim() == normalized_service_name)
+        else {
+            return Err(MeshRegistryError::InvalidDocument(format!(
+                "service '{}' is not registered",
+                normalized_service_name
+            )));
+        };
+        service.api_contracts.push(api_contract.to_string());
+        Self::from_document_with_env(
+            document,
+            &self.state.validation_options,
+            &self.enabled_gate_flags(),
+            self.state.api_contract_to_service_index.kind(),
+        )
+    }
+
     pub fn from_environment() -> Result<Option<Self>, MeshRegistryError> {
-        if let Ok(registry_json_source) = env::var(ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON) {
-            if !registry_json_source.trim().is_empty() {
-                return Ok(Some(Self::from_json_str(registry_json_source.as_str())?));
-            }
-        }
+        Self::from_environment_with(&ProcessEnv)
+    }
 
-        if let Ok(registry_path_source) = env::var(ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH) {
-            if !registry_path_source.trim().is_empty() {
-                return Ok(Some(Self::from_file_path(registry_path_source)?));
-            }
-        }
+    pub fn from_environment_with(env_source: &impl EnvSource) -> Result<Option<Self>, MeshRegistryError> {
+        let Some(registry) = Self::load_from_environment(env_source)? else {
+            return Ok(None);
+        };
+        Ok(Some(registry.enforce_environment_requirements(env_source)?))
+    }
+
+    /// Reloads from process env; see `reload_from_environment_with`. Registries loaded with a prefix must reload through
+    /// the same `PrefixedEnv` instead.
+    pub fn reload_from_environment(&mut self) -> Result<(), MeshRegistryError> {
+        self.reload_from_environment_with(&ProcessEnv)
+    }
+
+    /// Re-runs environment loading from `env_source` and swaps in the result only if it loads and validates; on error
+    /// `self` is left untouched. `WORLD_BUILDER_ENV` scoping, the strictness profile and contract gates are re-read from
+    /// `env_source` at reload time, so pass the same source (e.g. the same `PrefixedEnv`) the registry was loaded from.
+    /// Clones taken before the reload keep the previous registry.
+    pub fn reload_from_environment_with(
+        &mut self,
+        env_source: &impl EnvSource,
+    ) -> Result<(), MeshRegistryError> {
+        let Some(reloaded_registry) = Self::from_environment_with(env_source)? else {
+            return Err(MeshRegistryError::InvalidDocument(format!(
+                "cannot reload: neither {} nor {} is set",
+                ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH
+            )));
+        };
+        *self = reloaded_registry;
+        Ok(())
+    }
+
+    pub fn from_environment_with_prefix(prefix: &str) -> Result<Option<Self>, MeshRegistryError> {
+        Self::from_environment_with_prefix_with(prefix, &ProcessEnv)
+    }
 
-        Ok(None)
+    pub fn from_environment_with_prefix_with(
+        prefix: &str,
+        env_source: &impl EnvSource,
+    ) -> Result<Option<Self>, MeshRegistryError> {
+        Self::from_environment_with(&PrefixedEnv::new(prefix, env_source))
     }
 
     pub fn from_environment_or_single_service(
@@ -87,32 +488,371 @@ impl ServiceMeshRegistry {
         base_url: impl Into<String>,
         api_contracts: impl IntoIterator<Item = impl Into<String>>,
     ) -> Result<Self, MeshRegistryError> {
-        if let Some(registry) = Self::from_environment()? {
-            return Ok(registry);
-        }
-        Self::single_service(version, service_name, base_url, api_contracts)
+        Self::from_environment_or_single_service_with(&ProcessEnv, version, service_name, base_url, api_contracts)
+    }
+
+    pub fn from_environment_or_single_service_with(
+        env_source: &impl EnvSource,
+        version: impl Into<String>,
+        service_name: impl Into<String>,
+        base_url: impl Into<String>,
+        api_contracts: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, MeshRegistryError> {
+        let registry = match Self::load_from_environment(env_source)? {
+            Some(registry) => registry,
+            None => Self::single_service(version, service_name, base_url, api_contracts)?,
+        };
+        registry.enforce_environment_requirements(env_source)
     }
 
     pub fn version(&self) -> &str {
-        self.version.as_str()
+        self.state.version.as_str()
+    }
+
+    pub fn services(&self) -> &[ServiceRegistration] {
+        &self.state.services
+    }
+
+    pub fn service_metadata(
+        &self,
+        service_name: &str,
+    ) -> Option<&HashMap<String, String>> {
+        self.find_service(service_name).map(|service| &service.metadata)
+    }
+
+    pub fn service_name_for_contract(
+        &self,
+        api_contract: &str,
+    ) -> Option<&str> {
+        self.state
+            .api_contract_to_service_index
+            .get(self.canonical_api_contract(api_contract.trim()))
+            .map(|service_index| self.state.services[*service_index].service_name.as_str())
+    }
+
+    /// Whether both contracts (aliases honored, no `default_service` fallback) are owned by the same service.
+    pub fn same_service(
+        &self,
+        a: &str,
+        b: &str,
+    ) -> Result<bool, MeshRegistryError> {
+        Ok(self.registered_service_index(a)? == self.registered_service_index(b)?)
+    }
+
+    pub fn client_policy(
+        &self,
+        service_name: &str,
+    ) -> Option<&ClientPolicy> {
+        self.find_service(service_name)
+            .and_then(|service| service.client_policy.as_ref())
+    }
+
+    /// Joins the service's `health_path` (default `/healthz`) under its base URL path, so `https://host/svc` probes
+    /// `https://host/svc/healthz`; `None` for unknown or unix socket services.
+    pub fn health_url(
+        &self,
+        service_name: &str,
+    ) -> Option<Url> {
+        let service = self.find_service(service_name)?;
+        if unix_socket_path(&service.base_url).is_some() {
+            return None;
+        }
+        let health_path = service
+            .health_path
+            .as_deref()
+            .map_or(DEFAULT_SERVICE_HEALTH_PATH, str::trim);
+        let mut base_url = self.base_url_parsed(service_name)?;
+        if !base_url.path().ends_with('/') {
+            let base_path = format!("{}/", base_url.path());
+            base_url.set_path(&base_path);
+        }
+        base_url.join(health_path.trim_start_matches('/')).ok()
+    }
+
+    pub fn base_url_parsed(
+        &self,
+        service_name: &str,
+    ) -> Option<Url> {
+        let service_index = self.state.service_name_to_index.get(service_name.trim())?;
+        self.state.parsed_base_urls[*service_index].clone()
+    }
+
+    pub fn service_count(&self) -> usize {
+        self.state.services.len()
+    }
+
+    pub fn contract_count(&self) -> usize {
+        self.state.api_contract_to_service_index.len()
+    }
+
+    pub fn metrics_snapshot(&self) -> RegistryMetrics {
+        RegistryMetrics {
+            service_count: self.service_count(),
+            contract_count: self.contract_count(),
+            has_publish_policy: self.state.publish_ingress_policy.is_some(),
+            required_hop_count: self
+                .state
+                .publish_ingress_policy
+                .as_ref()
+                .map_or(0, |publish_ingress_policy| publish_ingress_policy.required_hops.len()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.services.is_empty()
+    }
+
+    pub fn contracts_in_namespace(
+        &self,
+        namespace: &str,
+    ) -> Vec<ResolvedServiceTarget> {
+        let namespace = namespace.trim().trim_end_matches('.');
+        let mut resolved_targets = self
+            .into_iter()
+            .filter(|resolved_target| is_in_api_contract_namespace(resolved_target.api_contract, namespace))
+            .map(ResolvedServiceTarget::from)
+            .collect::<Vec<_>>();
+        resolved_targets.sort_by(|left, right| left.api_contract.cmp(&right.api_contract));
+        resolved_targets
+    }
+
+    /// Pairs each service name with its contracts; services keep document order and contracts keep registration order.
+    pub fn topology(&self) -> Vec<(String, Vec<String>)> {
+        self.state
+            .services
+            .iter()
+            .map(|service| {
+                (
+                    service.service_name.trim().to_string(),
+                    service
+                        .api_contracts
+                        .iter()
+                        .map(|api_contract| api_contract.trim().to_string())
+                        .collect(),
+                )
+            })
+            .collect()
     }
 
     pub fn resolve_api_contract(
         &self,
         api_contract: &str,
     ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
-        let normalized_api_contract = api_contract.trim();
-        let Some(service_index) = self.api_contract_to_service_index.get(normalized_api_contract) else {
-            return Err(MeshRegistryError::UnknownApiContract(normalized_api_contract.to_string()));
-        };
-        let service = &self.services[*service_index];
-        Ok(ResolvedServiceTarget {
-            service_name: service.service_name.clone(),
-            base_url: service.base_url.clone(),
-            api_contract: normalized_api_contract.to_string(),
+        traced_resolution(api_contract, || {
+            let (registered_api_contract, service) = self.resolve_service_or_default(api_contract)?;
+            Ok(self
+                .resolved_target_ref(registered_api_contract, service)
+                .into())
         })
     }
 
+    /// Entry point for contracts that must be registered: equivalent to `ensure_contracts_registered([api_contract])`
+    /// followed by `resolve_api_contract`, so `default_service` fallback never satisfies it.
+    pub fn resolve_required(
+        &self,
+        api_contract: &str,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        self.ensure_contracts_registered([api_contract])?;
+        self.resolve_api_contract(api_contract)
+    }
+
+    /// Falls back to `fallback_base_url` when `api_contract` is not registered (and no `default_service` claims it).
+    ///
+    /// The fallback target bypasses the registry's routing guarantees: it has an empty `service_name`, is not checked
+    /// against registered services, and only has to pass the registry's `base_url` validation. Other resolution errors,
+    /// such as a disabled service or contract, are still returned.
+    pub fn resolve_api_contract_or(
+        &self,
+        api_contract: &str,
+        fallback_base_url: &str,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        match self.resolve_api_contract(api_contract) {
+            Err(MeshRegistryError::UnknownApiContract(_)) => {
+                validate_base_url("fallback", fallback_base_url, &self.state.validation_options)?;
+                Ok(ResolvedServiceTarget {
+                    service_name: String::new(),
+                    base_url: fallback_base_url.trim().to_string(),
+                    api_contract: api_contract.trim().to_string(),
+                    kind: ApiContractKind::default(),
+                    content_type: DEFAULT_API_CONTRACT_CONTENT_TYPE.to_string(),
+                    rate_limit: None,
+                    requires_auth: false,
+                    failover_base_urls: Vec::new(),
+                })
+            }
+            resolution => resolution,
+        }
+    }
+
+    pub fn resolve_api_contract_with(
+        &self,
+        env_source: &impl EnvSource,
+        api_contract: &str,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        let (registered_api_contract, service) = self.resolve_service_or_default_with(env_source, api_contract)?;
+        Ok(self
+            .resolved_target_ref(registered_api_contract, service)
+            .into())
+    }
+
+    /// Borrowing counterpart to `resolve_api_contract` for registered contracts; the `default_service` fallback only applies
+    /// to owned resolution, so unregistered contracts return `UnknownApiContract` here.
+    pub fn resolve_api_contract_ref(
+        &self,
+        api_contract: &str,
+    ) -> Result<ResolvedServiceTargetRef<'_>, MeshRegistryError> {
+        let (registered_api_contract, service) = self.resolve_registered_service(api_contract)?;
+        Ok(self.resolved_target_ref(registered_api_contract, service))
+    }
+
+    pub fn resolve_api_contract_in_region(
+        &self,
+        api_contract: &str,
+        region: &str,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        let (registered_api_contract, service) = self.resolve_service_or_default(api_contract)?;
+        let normalized_region = region.trim();
+        let regional_endpoint = routable_service_endpoints(service).find(|service_endpoint| {
+            service_endpoint
+                .metadata
+                .get(SERVICE_METADATA_REGION_KEY)
+                .is_some_and(|endpoint_region| endpoint_region.trim() == normalized_region)
+        });
+        let base_url = match regional_endpoint {
+            Some(service_endpoint) => service_endpoint.base_url,
+            None if self.state.validation_options.strict => {
+                return Err(MeshRegistryError::NoServiceInstanceInRegion {
+                    api_contract: registered_api_contract.to_string(),
+                    region: normalized_region.to_string(),
+                });
+            }
+            None => service.base_url.as_str(),
+        };
+
+        Ok(self.resolved_target_at(registered_api_contract, service, base_url))
+    }
+
+    pub fn resolve_api_contract_balanced(
+        &self,
+        api_contract: &str,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        let (registered_api_contract, service) = self.resolve_service_or_default(api_contract)?;
+        let service_index = self.state.service_name_to_index[service.service_name.trim()];
+        let selection = self.state.balanced_selection_counters[service_index].fetch_add(1, AtomicOrdering::Relaxed);
+        Ok(self.resolved_target_at(registered_api_contract, service, select_weighted_base_url(service, selection)))
+    }
+
+    pub fn resolve_api_contract_balanced_with_seed(
+        &self,
+        api_contract: &str,
+        seed: u64,
+    ) -> Result<ResolvedServiceTarget, MeshRegistryError> {
+        let (registered_api_contract, service) = self.resolve_service_or_default(api_contract)?;
+        Ok(self.resolved_target_at(registered_api_contract, service, select_weighted_base_url(service, mix_selection_seed(seed))))
+    }
+
+    pub fn latest_version_of(
+        &self,
+        api_contract_base: &str,
+    ) -> Option<ResolvedServiceTarget> {
+        let latest_api_contract = self.latest_registered_version_of(api_contract_base.trim())?;
+        self.resolve_api_contract(latest_api_contract).ok()
+    }
+
+    /// Diagnostic counterpart to `resolve_api_contract`: says whether the contract resolves, is disabled, lost its version
+    /// to another registered version, looks like a typo of a registered contract, or is simply absent.
+    pub fn explain_resolution(
+        &self,
+        api_contract: &str,
+    ) -> ResolutionExplanation {
+        match self.resolve_service_or_default(api_contract) {
+            Ok((registered_api_contract, service)) => ResolutionExplanation::Resolvable {
+                api_contract: registered_api_contract.to_string(),
+                service_name: service.service_name.trim().to_string(),
+            },
+            Err(MeshRegistryError::ServiceDisabled { service_name, api_contract }) => ResolutionExplanation::ServiceDisabled { api_contract, service_name },
+            Err(MeshRegistryError::ContractDisabled { api_contract, env_var }) => ResolutionExplanation::ContractDisabled { api_contract, env_var },
+            Err(_) => self.explain_unknown_api_contract(self.canonical_api_contract(api_contract.trim())),
+        }
+    }
+
+    pub fn suggest_service_for(
+        &self,
+        api_contract: &str,
+    ) -> Option<&str> {
+        let requested_segments = api_contract.trim().split('.').collect::<Vec<_>>();
+        let mut best_shared_segments = API_CONTRACT_NAMESPACE_SEGMENTS;
+        let mut suggested_service_index = None::<usize>;
+        let mut is_ambiguous = false;
+        for (registered_api_contract, service_index) in self.state.api_contract_to_service_index.iter() {
+            let shared_segments = registered_api_contract
+                .trim()
+                .split('.')
+                .zip(&requested_segments)
+                .take_while(|(registered_segment, requested_segment)| registered_segment == *requested_segment)
+                .count();
+            if shared_segments < best_shared_segments {
+                continue;
+            }
+            if shared_segments > best_shared_segments || suggested_service_index.is_none() {
+                best_shared_segments = shared_segments;
+                suggested_service_index = Some(*service_index);
+                is_ambiguous = false;
+            } else if suggested_service_index != Some(*service_index) {
+                is_ambiguous = true;
+            }
+        }
+
+        if is_ambiguous {
+            return None;
+        }
+        suggested_service_index.map(|service_index| self.state.services[service_index].service_name.trim())
+    }
+
+    pub fn resolve_route(
+        &self,
+        method: &str,
+        path: &str,
+    ) -> Option<ResolvedServiceTarget> {
+        let api_contract = self.state.route_table.match_route(method, path)?;
+        self.resolve_api_contract(api_contract).ok()
+    }
+
+    pub fn resolve_many(
+        &self,
+        api_contracts: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<ResolvedServiceTarget>, MeshRegistryError> {
+        api_contracts
+            .into_iter()
+            .map(|api_contract| self.resolve_api_contract(api_contract.as_ref()))
+            .collect()
+    }
+
+    pub fn resolve_many_partial(
+        &self,
+        api_contracts: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Vec<Result<ResolvedServiceTarget, MeshRegistryError>> {
+        api_contracts
+            .into_iter()
+            .map(|api_contract| self.resolve_api_contract(api_contract.as_ref()))
+            .collect()
+    }
+
+    pub fn ensure_version(
+        &self,
+        expected: &str,
+    ) -> Result<(), MeshRegistryError> {
+        let normalized_expected_version = expected.trim();
+        let normalized_actual_version = self.state.version.trim();
+        if normalized_actual_version != normalized_expected_version {
+            return Err(MeshRegistryError::VersionMismatch {
+                expected: normalized_expected_version.to_string(),
+                actual: normalized_actual_version.to_string(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn ensure_contracts_registered(
         &self,
         required_api_contracts: impl IntoIterator<Item = impl AsRef<str>>,
@@ -126,8 +866,9 @@ impl ServiceMeshRegistry {
                 ));
             }
             if !self
+                .state
                 .api_contract_to_service_index
-                .contains_key(normalized_api_contract)
+                .contains_key(self.canonical_api_contract(normalized_api_contract))
             {
                 missing_api_contracts.push(normalized_api_contract.to_string());
             }
@@ -142,31 +883,157 @@ impl ServiceMeshRegistry {
         Err(MeshRegistryError::MissingRequiredApiContracts(missing_api_contracts))
     }
 
+    /// Checks registered contracts against an expected contract -> service map; contracts missing from either side are ignored.
+    pub fn ensure_ownership(
+        &self,
+        expected: &HashMap<String, String>,
+    ) -> Result<(), MeshRegistryError> {
+        let mut expected_ownership = expected.iter().collect::<Vec<_>>();
+        expected_ownership.sort();
+        for (api_contract, expected_service) in expected_ownership {
+            let normalized_api_contract = self.canonical_api_contract(api_contract.trim());
+            let Some(service_index) = self
+                .state
+                .api_contract_to_service_index
+                .get(normalized_api_contract)
+            else {
+                continue;
+            };
+            let actual_service = self.state.services[*service_index].service_name.trim();
+            if actual_service != expected_service.trim() {
+                return Err(MeshRegistryError::OwnershipViolation {
+                    api_contract: normalized_api_contract.to_string(),
+                    expected_service: expected_service.trim().to_string(),
+                    actual_service: actual_service.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn unused_services(
+        &self,
+        in_use_api_contracts: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Vec<String> {
+        let mut service_in_use = vec![false; self.state.services.len()];
+        for in_use_api_contract in in_use_api_contracts {
+            let normalized_api_contract = self.canonical_api_contract(in_use_api_contract.as_ref().trim());
+            if let Some(service_index) = self
+                .state
+                .api_contract_to_service_index
+                .get(normalized_api_contract)
+            {
+                service_in_use[*service_index] = true;
+            }
+        }
+
+        self.state
+            .services
+            .iter()
+            .zip(service_in_use)
+            .filter(|(_, in_use)| !in_use)
+            .map(|(service, _)| service.service_name.trim().to_string())
+            .collect()
+    }
+
     pub fn publish_ingress_policy(&self) -> Option<&PublishIngressPolicy> {
         self.document_publish_ingress_policy()
     }
 
+    pub fn validate_contract_dependencies(&self) -> Result<(), MeshRegistryError> {
+        let mut canonical_api_contract_dependencies = BTreeMap::<&str, Vec<&str>>::new();
+        for (api_contract, dependencies) in &self.state.api_contract_dependencies {
+            let (registered_api_contract, _) = self.resolve_registered_contract_owner(api_contract)?;
+            let mut canonical_dependencies = Vec::<&str>::with_capacity(dependencies.len());
+            for dependency in dependencies {
+                let Ok((registered_dependency, _)) = self.resolve_registered_contract_owner(dependency) else {
+                    return Err(MeshRegistryError::MissingApiContractDependency {
+                        api_contract: registered_api_contract.to_string(),
+                        dependency: dependency.clone(),
+                    });
+                };
+                canonical_dependencies.push(registered_dependency);
+            }
+            canonical_api_contract_dependencies
+                .entry(registered_api_contract)
+                .or_default()
+                .extend(canonical_dependencies);
+        }
+
+        match find_api_contract_dependency_cycle(&canonical_api_contract_dependencies) {
+            Some(dependency_cycle) => Err(MeshRegistryError::ApiContractDependencyCycle(dependency_cycle)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn require_publish_ingress_policy_present(&self) -> Result<&PublishIngressPolicy, MeshRegistryError> {
+        self.require_publish_ingress_policy()
+    }
+
+    pub fn required_hops(&self) -> Option<&[PublishIngressRequiredHop]> {
+        self.document_publish_ingress_policy()
+            .map(|publish_ingress_policy| publish_ingress_policy.required_hops.as_slice())
+    }
+
+    pub fn publish_ingress_env_vars(&self) -> Vec<&str> {
+        self.document_publish_ingress_policy()
+            .map(|publish_ingress_policy| {
+                publish_ingress_policy
+                    .required_hops
+                    .iter()
+                    .map(|required_hop| required_hop.max_body_bytes_env_var.trim())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn hop_product(
+        &self,
+        hop_name: &str,
+    ) -> Result<&str, MeshRegistryError> {
+        self.resolve_publish_ingress_required_hop(hop_name)
+            .map(|required_hop| required_hop.product.trim())
+    }
+
     pub fn ensure_publish_ingress_hop_limit_from_environment(
         &self,
         hop_name: &str,
+    ) -> Result<PublishIngressHopRuntimeLimit, MeshRegistryError> {
+        self.ensure_publish_ingress_hop_limit_from_environment_with(&ProcessEnv, hop_name)
+    }
+
+    pub fn ensure_publish_ingress_hop_limit_from_environment_with(
+        &self,
+        env_source: &impl EnvSource,
+        hop_name: &str,
     ) -> Result<PublishIngressHopRuntimeLimit, MeshRegistryError> {
         let required_hop = self.resolve_publish_ingress_required_hop(hop_name)?;
-        let env_var_value = env::var(required_hop.max_body_bytes_env_var.as_str()).map_err(|_| MeshRegistryError::MissingPublishIngressHopLimit {
-            hop_name: required_hop.hop_name.clone(),
-            env_var: required_hop.max_body_bytes_env_var.clone(),
-        })?;
-        let parsed_limit = env_var_value
-            .parse::<u64>()
-            .map_err(|_| MeshRegistryError::InvalidPublishIngressHopLimit {
-                hop_name: required_hop.hop_name.clone(),
-                env_var: required_hop.max_body_bytes_env_var.clone(),
-                value: env_var_value.clone(),
-            })?;
-        self.ensure_publish_ingress_hop_limit(required_hop.hop_name.as_str(), parsed_limit)?;
-        Ok(PublishIngressHopRuntimeLimit {
-            hop_name: required_hop.hop_name.clone(),
-            configured_max_body_bytes: parsed_limit,
-        })
+        let configured_hop_limit = read_publish_ingress_hop_limit(env_source, required_hop)?;
+        self.ensure_publish_ingress_hop_limit(required_hop.hop_name.as_str(), configured_hop_limit.configured_max_body_bytes)?;
+        Ok(configured_hop_limit)
+    }
+
+    pub fn read_all_hop_limits_from_environment(&self) -> Result<Vec<PublishIngressHopRuntimeLimit>, MeshRegistryError> {
+        self.read_all_hop_limits_from_environment_with(&ProcessEnv)
+    }
+
+    pub fn read_all_hop_limits_from_environment_with(
+        &self,
+        env_source: &impl EnvSource,
+    ) -> Result<Vec<PublishIngressHopRuntimeLimit>, MeshRegistryError> {
+        let publish_ingress_policy = self.require_publish_ingress_policy()?;
+        let mut configured_hop_limits = Vec::<PublishIngressHopRuntimeLimit>::with_capacity(publish_ingress_policy.required_hops.len());
+        let mut hop_limit_errors = Vec::<MeshRegistryError>::new();
+        for required_hop in &publish_ingress_policy.required_hops {
+            match read_publish_ingress_hop_limit(env_source, required_hop) {
+                Ok(configured_hop_limit) => configured_hop_limits.push(configured_hop_limit),
+                Err(hop_limit_error) => hop_limit_errors.push(hop_limit_error),
+            }
+        }
+        if !hop_limit_errors.is_empty() {
+            return Err(MeshRegistryError::PublishIngressHopLimitErrors(hop_limit_errors));
+        }
+        Ok(configured_hop_limits)
     }
 
     pub fn ensure_publish_ingress_hop_limit(
@@ -174,28 +1041,34 @@ impl ServiceMeshRegistry {
         hop_name: &str,
         configured_max_body_bytes: u64,
     ) -> Result<(), MeshRegistryError> {
-        self.resolve_publish_ingress_required_hop(hop_name)?;
-        let publish_ingress_policy = self.require_publish_ingress_policy()?;
-        if configured_max_body_bytes < publish_ingress_policy.default_max_body_bytes {
-            return Err(MeshRegistryError::PublishIngressHopLimitTooLow {
-                hop_name: hop_name.trim().to_string(),
-                configured_max_body_bytes,
-                required_min_body_bytes: publish_ingress_policy.default_max_body_bytes,
-            });
-        }
-        Ok(())
+        traced_publish_ingress_conformance("hop_limit", hop_name, || {
+            let required_hop = self.resolve_publish_ingress_required_hop(hop_name)?;
+            let publish_ingress_policy = self.require_publish_ingress_policy()?;
+            let required_min_body_bytes = required_hop
+                .min_body_bytes
+                .unwrap_or(publish_ingress_policy.default_max_body_bytes);
+            if configured_max_body_bytes < required_min_body_bytes {
+                return Err(MeshRegistryError::PublishIngressHopLimitTooLow {
+                    hop_name: hop_name.trim().to_string(),
+                    configured_max_body_bytes,
+                    required_min_body_bytes,
+                });
+            }
+            Ok(())
+        })
     }
 
-    pub fn ensure_publish_ingress_all_hops_conform(
+    pub fn effective_publish_ingress_ceiling(
         &self,
         configured_hop_limits: impl IntoIterator<Item = PublishIngressHopRuntimeLimit>,
-    ) -> Result<(), MeshRegistryError> {
+    ) -> Result<u64, MeshRegistryError> {
         let publish_ingress_policy = self.require_publish_ingress_policy()?;
-        let mut configured_hop_limits_by_name = HashMap::<String, u64>::new();
-        for configured_hop_limit in configured_hop_limits {
-            configured_hop_limits_by_name.insert(configured_hop_limit.hop_name.trim().to_string(), configured_hop_limit.configured_max_body_bytes);
-        }
+        let configured_hop_limits_by_name = configured_hop_limits
+            .into_iter()
+            .map(|configured_hop_limit| (configured_hop_limit.hop_name.trim().to_string(), configured_hop_limit.configured_max_body_bytes))
+            .collect::<HashMap<_, _>>();
 
+        let mut effective_ceiling = u64::MAX;
         for required_hop in &publish_ingress_policy.required_hops {
             let Some(configured_max_body_bytes) = configured_hop_limits_by_name.get(required_hop.hop_name.trim()) else {
                 return Err(MeshRegistryError::MissingPublishIngressHopLimit {
@@ -203,14 +1076,458 @@ impl ServiceMeshRegistry {
                     env_var: required_hop.max_body_bytes_env_var.clone(),
                 });
             };
-            self.ensure_publish_ingress_hop_limit(required_hop.hop_name.as_str(), *configured_max_body_bytes)?;
+            effective_ceiling = effective_ceiling.min(*configured_max_body_bytes);
+        }
+        Ok(effective_ceiling)
+    }
+
+    pub fn ensure_publish_ingress_all_hops_conform(
+        &self,
+        configured_hop_limits: impl IntoIterator<Item = PublishIngressHopRuntimeLimit>,
+    ) -> Result<(), MeshRegistryError> {
+        traced_publish_ingress_conformance("all_hops", "", || {
+            let publish_ingress_policy = self.require_publish_ingress_policy()?;
+            let mut configured_hop_limits_by_name = HashMap::<String, u64>::new();
+            for configured_hop_limit in configured_hop_limits {
+                configured_hop_limits_by_name.insert(configured_hop_limit.hop_name.trim().to_string(), configured_hop_limit.configured_max_body_bytes);
+            }
+
+            for required_hop in &publish_ingress_policy.required_hops {
+                let Some(configured_max_body_bytes) = configured_hop_limits_by_name.get(required_hop.hop_name.trim()) else {
+                    return Err(MeshRegistryError::MissingPublishIngressHopLimit {
+                        hop_name: required_hop.hop_name.clone(),
+                        env_var: required_hop.max_body_bytes_env_var.clone(),
+                    });
+                };
+                self.ensure_publish_ingress_hop_limit(required_hop.hop_name.as_str(), *configured_max_body_bytes)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Rejects a proposed registry that would break in-flight callers, reporting the first breaking change: a removed
+    /// contract listed in `in_use_contracts`, an `https` -> `http` downgrade of any endpoint (`base_url`,
+    /// `instances[i].base_url` or `failover_base_urls[i]`, compared by position), or a contract moving services.
+    pub fn ensure_compatible_upgrade(
+        &self,
+        proposed: &ServiceMeshRegistry,
+        in_use_contracts: &[&str],
+    ) -> Result<(), MeshRegistryError> {
+        let registry_diff = self.diff(proposed);
+        for in_use_contract in in_use_contracts {
+            let registered_api_contract = self.canonical_api_contract(in_use_contract.trim());
+            if registry_diff
+                .api_contracts_removed
+                .iter()
+                .any(|removed_api_contract| removed_api_contract == registered_api_contract)
+                && proposed.service_name_for_contract(in_use_contract).is_none()
+            {
+                return Err(MeshRegistryError::IncompatibleUpgrade(format!(
+                    "in-use api contract '{}' is removed",
+                    registered_api_contract
+                )));
+            }
+        }
+        for current_service in &self.state.services {
+            let Some(proposed_service) = proposed.find_service(&current_service.service_name) else {
+                continue;
+            };
+            let proposed_endpoint_base_urls = labeled_endpoint_base_urls(proposed_service).collect::<HashMap<_, _>>();
+            for (endpoint_label, current_base_url) in labeled_endpoint_base_urls(current_service) {
+                if let Some(proposed_base_url) = proposed_endpoint_base_urls.get(&endpoint_label)
+                    && url_scheme_is(current_base_url, "https")
+                    && url_scheme_is(proposed_base_url, "http")
+                {
+                    return Err(MeshRegistryError::IncompatibleUpgrade(format!(
+                        "service '{}' {} downgrades from '{}' to '{}'",
+                        current_service.service_name.trim(),
+                        endpoint_label,
+                        current_base_url,
+                        proposed_base_url
+                    )));
+                }
+            }
+        }
+        if let Some(owner_change) = registry_diff.api_contract_owner_changes.first() {
+            return Err(MeshRegistryError::IncompatibleUpgrade(format!(
+                "api contract '{}' moves from service '{}' to '{}'",
+                owner_change.api_contract, owner_change.current_service_name, owner_change.proposed_service_name
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn to_canonical_json(&self) -> String {
+        let mut canonical_document = self.to_document();
+        canonical_document
+            .services
+            .sort_by(|left, right| left.service_name.trim().cmp(right.service_name.trim()));
+        for service in &mut canonical_document.services {
+            service.api_contracts.sort();
+        }
+        let canonical_value = sort_json_object_keys(serde_json::to_value(&canonical_document).expect("registry document serializes to json"));
+        serde_json::to_string_pretty(&canonical_value).expect("json value serializes to string")
+    }
+
+    pub fn fingerprint(&self) -> String {
+        Sha256::digest(self.to_canonical_json().as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    pub fn diff(
+        &self,
+        other: &ServiceMeshRegistry,
+    ) -> RegistryDiff {
+        diff_registry_services(
+            &self.state.services,
+            self.document_publish_ingress_policy(),
+            &other.state.services,
+            other.document_publish_ingress_policy(),
+        )
+    }
+
+    /// Human-readable rendering of `diff(other)` for deploy notes; identical registries produce an empty string.
+    pub fn changelog(
+        &self,
+        other: &ServiceMeshRegistry,
+    ) -> String {
+        render_registry_changelog(&self.diff(other), &self.state.services, &other.state.services)
+    }
+
+    /// Drops the lookup indexes and per-contract maps, returning the version, services and publish policy; the services are
+    /// moved out when this is the last handle to the registry and cloned otherwise.
+    pub fn into_parts(self) -> (String, Vec<ServiceRegistration>, Option<PublishIngressPolicy>) {
+        match Arc::try_unwrap(self.state) {
+            Ok(state) => (state.version, state.services, state.publish_ingress_policy),
+            Err(state) => (state.version.clone(), state.services.clone(), state.publish_ingress_policy.clone()),
+        }
+    }
+
+    /// Registered (canonical) name `api_contract` resolves to, or `None` when only `default_service` could serve it.
+    pub(crate) fn registered_api_contract(
+        &self,
+        api_contract: &str,
+    ) -> Option<&str> {
+        self.resolve_registered_contract_owner(api_contract)
+            .ok()
+            .map(|(registered_api_contract, _)| registered_api_contract)
+    }
+
+    pub(crate) fn to_document(&self) -> ServiceMeshRegistryDocument {
+        ServiceMeshRegistryDocument {
+            version: self.state.version.clone(),
+            services: self.state.services.clone(),
+            publish_ingress_policy: self.state.publish_ingress_policy.clone(),
+            api_contract_aliases: self
+                .state
+                .api_contract_aliases
+                .iter()
+                .map(|(alias, canonical_api_contract)| (alias.clone(), canonical_api_contract.clone()))
+                .collect(),
+            api_contract_dependencies: self.state.api_contract_dependencies.clone(),
+            api_contract_routes: self.state.api_contract_routes.clone(),
+            api_contract_kinds: self.state.api_contract_kinds.clone(),
+            api_contract_content_types: self.state.api_contract_content_types.clone(),
+            api_contract_rate_limits: self.state.api_contract_rate_limits.clone(),
+            api_contract_requires_auth: self.state.api_contract_requires_auth.clone(),
+            api_contract_environments: self.state.api_contract_environments.clone(),
+            api_contract_enabled_when_env: self.state.api_contract_enabled_when_env.clone(),
+            default_service: self.state.default_service.clone(),
+        }
+    }
+
+    fn load_from_environment(env_source: &impl EnvSource) -> Result<Option<Self>, MeshRegistryError> {
+        let validation_options = strictness_profile_from_environment(env_source)?.validation_options();
+        let document = if let Some(registry_json_source) = env_source.var(ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON)
+            && !registry_json_source.trim().is_empty()
+        {
+            decode_json_document_with_options(registry_json_source.as_str(), &validation_options)?
+        } else if let Some(registry_path_source) = env_source.var(ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH)
+            && !registry_path_source.trim().is_empty()
+        {
+            decode_file_document_with_options(Path::new(&registry_path_source), &validation_options)?
+        } else {
+            return Ok(None);
+        };
+        let document = match env_source.var(ENV_WORLD_BUILDER_ENV) {
+            Some(environment) if !environment.trim().is_empty() => {
+                let scoped_document = document.scoped_to_environment(&environment);
+                if scoped_document.services.is_empty() {
+                    return Err(MeshRegistryError::InvalidDocument(format!(
+                        "no service registrations apply to {} '{}'",
+                        ENV_WORLD_BUILDER_ENV,
+                        environment.trim()
+                    )));
+                }
+                scoped_document
+            }
+            _ => document,
+        };
+        Ok(Some(Self::from_document_with_env(
+            document,
+            &validation_options,
+            env_source,
+            ApiContractIndexKind::Hashed,
+        )?))
+    }
+
+    fn enforce_environment_requirements(
+        self,
+        env_source: &impl EnvSource,
+    ) -> Result<Self, MeshRegistryError> {
+        if env_flag_enabled(env_source, ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY)
+            || strictness_profile_from_environment(env_source)?.requires_publish_ingress_policy()
+        {
+            self.require_publish_ingress_policy_present()?;
+        }
+        Ok(self)
+    }
+
+    fn resolved_target_ref<'a>(
+        &'a self,
+        registered_api_contract: &'a str,
+        service: &'a ServiceRegistration,
+    ) -> ResolvedServiceTargetRef<'a> {
+        ResolvedServiceTargetRef {
+            service_name: service.service_name.as_str(),
+            base_url: service.base_url.as_str(),
+            api_contract: registered_api_contract,
+            kind: self.api_contract_kind(registered_api_contract),
+            content_type: self.api_contract_content_type(registered_api_contract),
+            rate_limit: self
+                .state
+                .api_contract_rate_limits
+                .get(registered_api_contract)
+                .copied(),
+            requires_auth: self
+                .state
+                .api_contract_requires_auth
+                .get(registered_api_contract)
+                .copied()
+                .unwrap_or_default(),
+            failover_base_urls: &service.failover_base_urls,
+        }
+    }
+
+    fn resolved_target_at(
+        &self,
+        registered_api_contract: &str,
+        service: &ServiceRegistration,
+        base_url: &str,
+    ) -> ResolvedServiceTarget {
+        ResolvedServiceTarget {
+            base_url: base_url.to_string(),
+            ..self
+                .resolved_target_ref(registered_api_contract, service)
+                .into()
+        }
+    }
+
+    fn explain_unknown_api_contract(
+        &self,
+        api_contract: &str,
+    ) -> ResolutionExplanation {
+        if let Some(latest_api_contract) =
+            parse_api_contract_version(api_contract).and_then(|(api_contract_base, _)| self.latest_registered_version_of(api_contract_base))
+        {
+            return ResolutionExplanation::VersionRemoved {
+                api_contract: api_contract.to_string(),
+                latest_api_contract: latest_api_contract.to_string(),
+            };
+        }
+        let registered_api_contracts = self.state.api_contract_to_service_index.keys();
+        match closest_api_contract(api_contract, registered_api_contracts) {
+            Some(suggestion) => ResolutionExplanation::Misspelled {
+                api_contract: api_contract.to_string(),
+                suggestion: suggestion.to_string(),
+            },
+            None => ResolutionExplanation::Absent {
+                api_contract: api_contract.to_string(),
+            },
         }
+    }
+
+    fn latest_registered_version_of(
+        &self,
+        api_contract_base: &str,
+    ) -> Option<&str> {
+        self.state
+            .api_contract_to_service_index
+            .keys()
+            .filter_map(|api_contract| {
+                let (registered_api_contract_base, version) = parse_api_contract_version(api_contract)?;
+                (registered_api_contract_base == api_contract_base).then_some((version, api_contract))
+            })
+            .max()
+            .map(|(_, api_contract)| api_contract)
+    }
+
+    fn resolve_service_or_default<'a>(
+        &'a self,
+        api_contract: &'a str,
+    ) -> Result<(&'a str, &'a ServiceRegistration), MeshRegistryError> {
+        self.resolve_service_or_default_gated(api_contract, |registered_api_contract, _| self.is_stored_gate_enabled(registered_api_contract))
+    }
+
+    fn resolve_service_or_default_with<'a>(
+        &'a self,
+        env_source: &impl EnvSource,
+        api_contract: &'a str,
+    ) -> Result<(&'a str, &'a ServiceRegistration), MeshRegistryError> {
+        self.resolve_service_or_default_gated(api_contract, |_, enabled_when_env| env_flag_enabled(env_source, enabled_when_env))
+    }
 
+    /// Like `resolve_service_or_default` without the `default_service` fallback, so the result borrows only from `self`.
+    fn resolve_registered_service(
+        &self,
+        api_contract: &str,
+    ) -> Result<(&str, &ServiceRegistration), MeshRegistryError> {
+        let (registered_api_contract, service) = self.resolve_registered_contract_owner(api_contract)?;
+        self.ensure_service_routable(registered_api_contract, service, |registered_api_contract, _| {
+            self.is_stored_gate_enabled(registered_api_contract)
+        })?;
+        Ok((registered_api_contract, service))
+    }
+
+    fn resolve_service_or_default_gated<'a>(
+        &'a self,
+        api_contract: &'a str,
+        is_gate_enabled: impl Fn(&str, &str) -> bool,
+    ) -> Result<(&'a str, &'a ServiceRegistration), MeshRegistryError> {
+        let (registered_api_contract, service) = match self.resolve_registered_contract_owner(api_contract) {
+            Err(MeshRegistryError::UnknownApiContract(unknown_api_contract)) => {
+                let normalized_api_contract = api_contract.trim();
+                let Some(default_service_index) = self
+                    .state
+                    .default_service_index
+                    .filter(|_| !normalized_api_contract.is_empty())
+                else {
+                    return Err(MeshRegistryError::UnknownApiContract(unknown_api_contract));
+                };
+                (normalized_api_contract, &self.state.services[default_service_index])
+            }
+            registered_contract_owner => registered_contract_owner?,
+        };
+        self.ensure_service_routable(registered_api_contract, service, is_gate_enabled)?;
+        Ok((registered_api_contract, service))
+    }
+
+    fn ensure_service_routable(
+        &self,
+        api_contract: &str,
+        service: &ServiceRegistration,
+        is_gate_enabled: impl Fn(&str, &str) -> bool,
+    ) -> Result<(), MeshRegistryError> {
+        if !service.enabled {
+            return Err(MeshRegistryError::ServiceDisabled {
+                service_name: service.service_name.trim().to_string(),
+                api_contract: api_contract.to_string(),
+            });
+        }
+        if let Some(enabled_when_env) = self.state.api_contract_enabled_when_env.get(api_contract)
+            && !is_gate_enabled(api_contract, enabled_when_env)
+        {
+            return Err(MeshRegistryError::ContractDisabled {
+                api_contract: api_contract.to_string(),
+                env_var: enabled_when_env.clone(),
+            });
+        }
         Ok(())
     }
 
+    fn is_stored_gate_enabled(
+        &self,
+        registered_api_contract: &str,
+    ) -> bool {
+        self.state
+            .enabled_gated_api_contracts
+            .contains(registered_api_contract)
+    }
+
+    /// Env source replaying the gate flags read at build time, for rebuilding derived registries.
+    fn enabled_gate_flags(&self) -> BTreeMap<String, String> {
+        self.state
+            .enabled_gated_api_contracts
+            .iter()
+            .filter_map(|api_contract| self.state.api_contract_enabled_when_env.get(api_contract))
+            .map(|enabled_when_env| (enabled_when_env.clone(), "true".to_string()))
+            .collect()
+    }
+
+    fn resolve_registered_contract_owner(
+        &self,
+        api_contract: &str,
+    ) -> Result<(&str, &ServiceRegistration), MeshRegistryError> {
+        let normalized_api_contract = self.canonical_api_contract(api_contract.trim());
+        let Some((registered_api_contract, service_index)) = self
+            .state
+            .api_contract_to_service_index
+            .get_key_value(normalized_api_contract)
+        else {
+            return Err(MeshRegistryError::UnknownApiContract(normalized_api_contract.to_string()));
+        };
+        Ok((registered_api_contract, &self.state.services[*service_index]))
+    }
+
+    fn registered_service_index(
+        &self,
+        api_contract: &str,
+    ) -> Result<usize, MeshRegistryError> {
+        let normalized_api_contract = self.canonical_api_contract(api_contract.trim());
+        self.state
+            .api_contract_to_service_index
+            .get(normalized_api_contract)
+            .copied()
+            .ok_or_else(|| MeshRegistryError::UnknownApiContract(normalized_api_contract.to_string()))
+    }
+
+    fn find_service(
+        &self,
+        service_name: &str,
+    ) -> Option<&ServiceRegistration> {
+        self.state
+            .service_name_to_index
+            .get(service_name.trim())
+            .map(|service_index| &self.state.services[*service_index])
+    }
+
+    fn api_contract_kind(
+        &self,
+        registered_api_contract: &str,
+    ) -> ApiContractKind {
+        self.state
+            .api_contract_kinds
+            .get(registered_api_contract)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn api_contract_content_type(
+        &self,
+        registered_api_contract: &str,
+    ) -> &str {
+        self.state
+            .api_contract_content_types
+            .get(registered_api_contract)
+            .map_or(DEFAULT_API_CONTRACT_CONTENT_TYPE, String::as_str)
+    }
+
+    fn canonical_api_contract<'a>(
+        &'a self,
+        api_contract: &'a str,
+    ) -> &'a str {
+        self.state
+            .api_contract_aliases
+            .get(api_contract)
+            .map(String::as_str)
+            .unwrap_or(api_contract)
+    }
+
     fn document_publish_ingress_policy(&self) -> Option<&PublishIngressPolicy> {
-        self.publish_ingress_policy.as_ref()
+        self.state.publish_ingress_policy.as_ref()
     }
 
     fn require_publish_ingress_policy(&self) -> Result<&PublishIngressPolicy, MeshRegistryError> {
@@ -231,3 +1548,197 @@ impl ServiceMeshRegistry {
             .ok_or_else(|| MeshRegistryError::MissingPublishIngressHop(normalized_hop_name.to_string()))
     }
 }
+
+const API_CONTRACT_NAMESPACE_SEGMENTS: usize = 2;
+
+#[derive(Clone, Debug)]
+pub struct ResolvedServiceTargets<'a> {
+    registry: &'a ServiceMeshRegistry,
+    service_index: usize,
+    api_contract_index: usize,
+}
+
+impl<'a> Iterator for ResolvedServiceTargets<'a> {
+    type Item = ResolvedServiceTargetRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let services = &self.registry.state.services;
+        while let Some(service) = services.get(self.service_index) {
+            if let Some(api_contract) = service.api_contracts.get(self.api_contract_index) {
+                self.api_contract_index += 1;
+                return Some(self.registry.resolved_target_ref(api_contract.trim(), service));
+            }
+            self.service_index += 1;
+            self.api_contract_index = 0;
+        }
+        None
+    }
+}
+
+impl<'a> IntoIterator for &'a ServiceMeshRegistry {
+    type Item = ResolvedServiceTargetRef<'a>;
+    type IntoIter = ResolvedServiceTargets<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ResolvedServiceTargets {
+            registry: self,
+            service_index: 0,
+            api_contract_index: 0,
+        }
+    }
+}
+
+fn is_in_api_contract_namespace(
+    api_contract: &str,
+    namespace: &str,
+) -> bool {
+    namespace.is_empty()
+        || api_contract
+            .strip_prefix(namespace)
+            .is_some_and(|remainder| remainder.is_empty() || remainder.starts_with('.'))
+}
+
+struct ServiceEndpoint<'a> {
+    base_url: &'a str,
+    metadata: &'a HashMap<String, String>,
+    weight: u32,
+}
+
+/// Every endpoint URL of a service keyed by its document position, so upgrades can compare like for like.
+fn labeled_endpoint_base_urls(service: &ServiceRegistration) -> impl Iterator<Item = (String, &str)> {
+    let instance_base_urls = service
+        .instances
+        .iter()
+        .enumerate()
+        .map(|(instance_index, instance)| (format!("instances[{}].base_url", instance_index), instance.base_url.trim()));
+    let failover_base_urls = service
+        .failover_base_urls
+        .iter()
+        .enumerate()
+        .map(|(failover_index, failover_base_url)| (format!("failover_base_urls[{}]", failover_index), failover_base_url.trim()));
+    std::iter::once(("base_url".to_string(), service.base_url.trim()))
+        .chain(instance_base_urls)
+        .chain(failover_base_urls)
+}
+
+fn routable_service_endpoints(service: &ServiceRegistration) -> impl Iterator<Item = ServiceEndpoint<'_>> {
+    let primary_endpoint = ServiceEndpoint {
+        base_url: service.base_url.as_str(),
+        metadata: &service.metadata,
+        weight: service.weight,
+    };
+    let instance_endpoints = service.instances.iter().map(|instance| ServiceEndpoint {
+        base_url: instance.base_url.as_str(),
+        metadata: &instance.metadata,
+        weight: instance.weight,
+    });
+    std::iter::once(primary_endpoint)
+        .chain(instance_endpoints)
+        .filter(|service_endpoint| service_endpoint.weight > 0)
+}
+
+fn read_publish_ingress_hop_limit(
+    env_source: &impl EnvSource,
+    required_hop: &PublishIngressRequiredHop,
+) -> Result<PublishIngressHopRuntimeLimit, MeshRegistryError> {
+    let env_var_value = env_source
+        .var(required_hop.max_body_bytes_env_var.as_str())
+        .ok_or_else(|| MeshRegistryError::MissingPublishIngressHopLimit {
+            hop_name: required_hop.hop_name.clone(),
+            env_var: required_hop.max_body_bytes_env_var.clone(),
+        })?;
+    let configured_max_body_bytes = parse_byte_size(&env_var_value).map_err(|reason| MeshRegistryError::InvalidPublishIngressHopLimit {
+        hop_name: required_hop.hop_name.clone(),
+        env_var: required_hop.max_body_bytes_env_var.clone(),
+        value: env_var_value.clone(),
+        reason,
+    })?;
+    Ok(PublishIngressHopRuntimeLimit {
+        hop_name: required_hop.hop_name.clone(),
+        configured_max_body_bytes,
+    })
+}
+
+/// Sorts object keys explicitly so canonical output does not depend on `serde_json::Map`'s backing order.
+fn sort_json_object_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut entries = object
+                .into_iter()
+                .map(|(key, value)| (key, sort_json_object_keys(value)))
+                .collect::<Vec<_>>();
+            entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+            serde_json::Value::Object(entries.into_iter().collect())
+        }
+        serde_json::Value::Array(values) => serde_json::Value::Array(values.into_iter().map(sort_json_object_keys).collect()),
+        value => value,
+    }
+}
+
+fn url_scheme_is(
+    base_url: &str,
+    scheme: &str,
+) -> bool {
+    Url::parse(base_url.trim()).is_ok_and(|parsed_base_url| parsed_base_url.scheme() == scheme)
+}
+
+fn strictness_profile_from_environment(env_source: &impl EnvSource) -> Result<StrictnessProfile, MeshRegistryError> {
+    let Some(strictness_value) = env_source
+        .var(ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS)
+        .filter(|strictness_value| !strictness_value.trim().is_empty())
+    else {
+        return Ok(StrictnessProfile::default());
+    };
+    StrictnessProfile::from_env_value(&strictness_value).ok_or_else(|| {
+        MeshRegistryError::InvalidDocument(format!(
+            "{} must be one of dev, staging, prod, got '{}'",
+            ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS,
+            strictness_value.trim()
+        ))
+    })
+}
+
+fn select_weighted_base_url(
+    service: &ServiceRegistration,
+    selection: u64,
+) -> &str {
+    let total_weight = routable_service_endpoints(service)
+        .map(|service_endpoint| u64::from(service_endpoint.weight))
+        .sum::<u64>();
+    let mut remaining_weight = selection % total_weight.max(1);
+    routable_service_endpoints(service)
+        .find(|service_endpoint| {
+            if remaining_weight < u64::from(service_endpoint.weight) {
+                return true;
+            }
+            remaining_weight -= u64::from(service_endpoint.weight);
+            false
+        })
+        .map_or(service.base_url.as_str(), |service_endpoint| service_endpoint.base_url)
+}
+
+fn mix_selection_seed(seed: u64) -> u64 {
+    let mut mixed_seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    mixed_seed = (mixed_seed ^ (mixed_seed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    mixed_seed = (mixed_seed ^ (mixed_seed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    mixed_seed ^ (mixed_seed >> 31)
+}
+
+impl fmt::Display for ServiceMeshRegistry {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            formatter,
+            "service mesh registry version '{}': {} services, {} api contracts",
+            self.version(),
+            self.service_count(),
+            self.contract_count()
+        )?;
+        for service in self.services() {
+            write!(formatter, "\n  {}: {} api contracts", service.service_name.trim(), service.api_contracts.len())?;
+        }
+        Ok(())
+    }
+}
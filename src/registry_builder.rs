@@ -0,0 +1,67 @@
+use crate::api_contract_index::ApiContractIndexKind;
+use crate::env_source::{EnvSource, ProcessEnv};
+use crate::error::MeshRegistryError;
+use crate::models::{RegistryValidationOptions, ServiceMeshRegistryDocument};
+use crate::registry::ServiceMeshRegistry;
+
+/// Builds a `ServiceMeshRegistry` from a document with build-time choices that are not validation rules, such as the
+/// contract lookup structure and the `EnvSource` contract gates are read from.
+#[derive(Debug)]
+pub struct ServiceMeshRegistryBuilder<E: EnvSource = ProcessEnv> {
+    document: ServiceMeshRegistryDocument,
+    validation_options: RegistryValidationOptions,
+    api_contract_index_kind: ApiContractIndexKind,
+    env_source: E,
+}
+
+impl ServiceMeshRegistryBuilder {
+    pub fn new(document: ServiceMeshRegistryDocument) -> Self {
+        Self {
+            document,
+            validation_options: RegistryValidationOptions::default(),
+            api_contract_index_kind: ApiContractIndexKind::default(),
+            env_source: ProcessEnv,
+        }
+    }
+}
+
+impl<E: EnvSource> ServiceMeshRegistryBuilder<E> {
+    pub fn validation_options(
+        self,
+        validation_options: &RegistryValidationOptions,
+    ) -> Self {
+        Self {
+            validation_options: *validation_options,
+            ..self
+        }
+    }
+
+    pub fn api_contract_index(
+        self,
+        api_contract_index_kind: ApiContractIndexKind,
+    ) -> Self {
+        Self {
+            api_contract_index_kind,
+            ..self
+        }
+    }
+
+    /// Reads `api_contract_enabled_when_env` flags from `env_source` instead of the process environment.
+    pub fn env_source<F: EnvSource>(
+        self,
+        env_source: F,
+    ) -> ServiceMeshRegistryBuilder<F> {
+        ServiceMeshRegistryBuilder {
+            document: self.document,
+            validation_options: self.validation_options,
+            api_contract_index_kind: self.api_contract_index_kind,
+            env_source,
+        }
+    }
+
+    /// Validates and builds the registry, reading contract gates once from the configured `EnvSource` (process env
+    /// unless `env_source` was called).
+    pub fn build(self) -> Result<ServiceMeshRegistry, MeshRegistryError> {
+        ServiceMeshRegistry::from_document_with_env(self.document, &self.validation_options, &self.env_source, self.api_contract_index_kind)
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::constants::REGISTRY_DIRECTORY_VERSION_FILE_NAME;
+use crate::error::MeshRegistryError;
+use crate::registry::strip_registry_source_preamble;
+
+/// Assembles a registry document value from `registry_dir`.
+///
+/// Every `*.json` file (in file name order) is either a single service registration or a partial document with a
+/// `services` array plus any other document-level keys. Services and `api_contract_*` map entries are merged with
+/// duplicate detection; any other document field may only be set by one fragment.
+/// `version` overrides `_version.json`, which is otherwise required.
+pub(crate) fn assemble_registry_directory(
+    registry_dir: &Path,
+    version: Option<&str>,
+) -> Result<Value, MeshRegistryError> {
+    let mut fragment_paths = Vec::<PathBuf>::new();
+    for dir_entry in fs::read_dir(registry_dir).map_err(|io_error| MeshRegistryError::Io(format!("{}: {}", registry_dir.display(), io_error)))? {
+        let fragment_path = dir_entry
+            .map_err(|io_error| MeshRegistryError::Io(format!("{}: {}", registry_dir.display(), io_error)))?
+            .path();
+        let is_json_file = fragment_path.is_file()
+            && fragment_path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        if is_json_file {
+            fragment_paths.push(fragment_path);
+        }
+    }
+    fragment_paths.sort();
+
+    let mut assembled_document = Map::new();
+    let mut service_sources = HashMap::<String, String>::new();
+    let mut services = Vec::<Value>::new();
+    let mut directory_version = None;
+    for fragment_path in &fragment_paths {
+        let fragment_name = fragment_path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let fragment = read_fragment(fragment_path, &fragment_name)?;
+        if fragment_name == REGISTRY_DIRECTORY_VERSION_FILE_NAME {
+            directory_version = fragment.get("version").cloned();
+            continue;
+        }
+
+        let (fragment_services, document_fields) = if fragment.contains_key("services") {
+            let mut document_fields = fragment;
+            let Some(Value::Array(fragment_services)) = document_fields.remove("services") else {
+                return Err(MeshRegistryError::InvalidDocument(format!(
+                    "registry fragment '{}' services must be an array",
+                    fragment_name
+                )));
+            };
+            document_fields.remove("version");
+            (fragment_services, document_fields)
+        } else {
+            (vec![Value::Object(fragment)], Map::new())
+        };
+
+        for fragment_service in fragment_services {
+            let service_name = fragment_service
+                .get("service_name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            if let Some(first_fragment_name) = service_sources
+                .get(&service_name)
+                .filter(|_| !service_name.is_empty())
+            {
+                return Err(MeshRegistryError::InvalidDocument(format!(
+                    "service '{}' is defined in both registry fragments '{}' and '{}'",
+                    service_name, first_fragment_name, fragment_name
+                )));
+            }
+            service_sources.insert(service_name, fragment_name.clone());
+            services.push(fragment_service);
+        }
+
+        for (field_name, field_value) in document_fields {
+            merge_document_field(&mut assembled_document, &fragment_name, field_name, field_value)?;
+        }
+    }
+
+    let version = match version {
+        Some(version) => Value::String(version.to_string()),
+        None => directory_version.ok_or_else(|| {
+            MeshRegistryError::InvalidDocument(format!(
+                "registry directory '{}' is missing '{}' with a version",
+                registry_dir.display(),
+                REGISTRY_DIRECTORY_VERSION_FILE_NAME
+            ))
+        })?,
+    };
+    assembled_document.insert("version".to_string(), version);
+    assembled_document.insert("services".to_string(), Value::Array(services));
+    Ok(Value::Object(assembled_document))
+}
+
+fn read_fragment(
+    fragment_path: &Path,
+    fragment_name: &str,
+) -> Result<Map<String, Value>, MeshRegistryError> {
+    let fragment_source = fs::read_to_string(fragment_path).map_err(|io_error| MeshRegistryError::Io(format!("{}: {}", fragment_path.display(), io_error)))?;
+    match serde_json::from_str::<Value>(strip_registry_source_preamble(&fragment_source)) {
+        Ok(Value::Object(fragment)) => Ok(fragment),
+        Ok(_) => Err(MeshRegistryError::InvalidDocument(format!(
+            "registry fragment '{}' must be a json object",
+            fragment_name
+        ))),
+        Err(decode_error) => Err(MeshRegistryError::Decode(format!("{}: {}", fragment_name, decode_error))),
+    }
+}
+
+fn merge_document_field(
+    assembled_document: &mut Map<String, Value>,
+    fragment_name: &str,
+    field_name: String,
+    field_value: Value,
+) -> Result<(), MeshRegistryError> {
+    let Some(existing_value) = assembled_document.get_mut(&field_name) else {
+        assembled_document.insert(field_name, field_value);
+        return Ok(());
+    };
+    let (true, Value::Object(existing_entries), Value::Object(fragment_entries)) = (field_name.starts_with("api_contract_"), existing_value, field_value)
+    else {
+        return Err(MeshRegistryError::InvalidDocument(format!(
+            "registry fragment '{}' redefines document field '{}'",
+            fragment_name, field_name
+        )));
+    };
+    for (entry_key, entry_value) in fragment_entries {
+        if existing_entries.contains_key(&entry_key) {
+            return Err(MeshRegistryError::InvalidDocument(format!(
+                "registry fragment '{}' redefines {}['{}']",
+                fragment_name, field_name, entry_key
+            )));
+        }
+        existing_entries.insert(entry_key, entry_value);
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! `require_contracts!` declares a service's fixed contract dependencies once, as crate `API_*` constants.
+//!
+//! The macro expands to a `const` slice and a function that passes it to
+//! `ServiceMeshRegistry::ensure_contracts_registered`, so adding or removing a dependency is one line and a
+//! misspelled constant fails to compile.
+//!
+//! ```
+//! use backend_service_networking::{MVP_ANON_2D_GATEWAY_API_CONTRACTS, ServiceMeshRegistry, require_contracts};
+//!
+//! require_contracts! {
+//!     const CATALOG_API_CONTRACTS = [API_DISCOVERY_CATALOG_V1, API_DISCOVERY_DETAIL_V1];
+//!     fn ensure_catalog_contracts_registered;
+//! }
+//!
+//! let registry = ServiceMeshRegistry::single_service(
+//!     "2026-02-21",
+//!     "backend-data-center",
+//!     "https://127.0.0.1:8787",
+//!     MVP_ANON_2D_GATEWAY_API_CONTRACTS,
+//! )?;
+//! assert_eq!(CATALOG_API_CONTRACTS.len(), 2);
+//! ensure_catalog_contracts_registered(&registry)?;
+//! # Ok::<(), backend_service_networking::MeshRegistryError>(())
+//! ```
+
+#[macro_export]
+macro_rules! require_contracts {
+    (
+        $const_vis:vis const $const_name:ident = [$($api_contract:ident),+ $(,)?];
+        $fn_vis:vis fn $fn_name:ident;
+    ) => {
+        $const_vis const $const_name: &[&str] = &[$($crate::$api_contract),+];
+
+        $fn_vis fn $fn_name(registry: &$crate::ServiceMeshRegistry) -> ::core::result::Result<(), $crate::MeshRegistryError> {
+            registry.ensure_contracts_registered($const_name)
+        }
+    };
+}
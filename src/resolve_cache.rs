@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::error::MeshRegistryError;
+use crate::models::ResolvedServiceTarget;
+use crate::registry::ServiceMeshRegistry;
+
+/// Memoizes successful `resolve_api_contract` results by registered contract name.
+///
+/// Aliases and untrimmed names share the entry of the contract they resolve to, so the cache never outgrows the
+/// registry. `default_service` fallbacks and failed resolutions are not cached. Contract gates are read once when the
+/// registry is built, so cached entries cannot go stale until the registry is replaced.
+#[derive(Debug)]
+pub struct ResolvedTargetCache {
+    registry: ServiceMeshRegistry,
+    resolved_targets: RwLock<HashMap<String, Arc<ResolvedServiceTarget>>>,
+}
+
+impl ResolvedTargetCache {
+    pub fn new(registry: ServiceMeshRegistry) -> Self {
+        Self {
+            registry,
+            resolved_targets: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn registry(&self) -> &ServiceMeshRegistry {
+        &self.registry
+    }
+
+    pub fn resolve_cached(
+        &self,
+        api_contract: &str,
+    ) -> Result<Arc<ResolvedServiceTarget>, MeshRegistryError> {
+        let Some(registered_api_contract) = self.registry.registered_api_contract(api_contract) else {
+            return self.registry.resolve_api_contract(api_contract).map(Arc::new);
+        };
+        if let Some(resolved_target) = self
+            .resolved_targets
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(registered_api_contract)
+        {
+            return Ok(Arc::clone(resolved_target));
+        }
+
+        let resolved_target = Arc::new(self.registry.resolve_api_contract(registered_api_contract)?);
+        Ok(Arc::clone(
+            self.resolved_targets
+                .write()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .entry(registered_api_contract.to_string())
+                .or_insert(resolved_target),
+        ))
+    }
+
+    pub fn cached_len(&self) -> usize {
+        self.resolved_targets
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+    }
+
+    pub fn into_inner(self) -> ServiceMeshRegistry {
+        self.registry
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use crate::models::ApiContractRoute;
+
+#[derive(Debug, Default)]
+pub(crate) struct RouteTable {
+    routes: Vec<RouteTemplate>,
+}
+
+#[derive(Debug)]
+struct RouteTemplate {
+    method: String,
+    segments: Vec<RouteSegment>,
+    api_contract: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RouteSegment {
+    Literal(String),
+    Parameter,
+}
+
+impl RouteTable {
+    pub(crate) fn from_api_contract_routes(api_contract_routes: &BTreeMap<String, ApiContractRoute>) -> Self {
+        Self {
+            routes: api_contract_routes
+                .iter()
+                .map(|(api_contract, route)| RouteTemplate {
+                    method: normalize_route_method(&route.method),
+                    segments: parse_route_segments(&route.path),
+                    api_contract: api_contract.trim().to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn match_route(
+        &self,
+        method: &str,
+        path: &str,
+    ) -> Option<&str> {
+        let normalized_method = normalize_route_method(method);
+        let request_segments = request_path_segments(path);
+        let mut best_match = None::<(&RouteTemplate, Vec<bool>)>;
+        for route in &self.routes {
+            if route.method != normalized_method || !route_matches(route, &request_segments) {
+                continue;
+            }
+            let specificity = route
+                .segments
+                .iter()
+                .map(|segment| matches!(segment, RouteSegment::Literal(_)))
+                .collect::<Vec<_>>();
+            if best_match
+                .as_ref()
+                .is_none_or(|(_, best_specificity)| specificity > *best_specificity)
+            {
+                best_match = Some((route, specificity));
+            }
+        }
+        best_match.map(|(route, _)| route.api_contract.as_str())
+    }
+}
+
+pub(crate) fn normalize_route_method(method: &str) -> String {
+    method.trim().to_ascii_uppercase()
+}
+
+pub(crate) fn route_template_key(route: &ApiContractRoute) -> String {
+    let template_path = parse_route_segments(&route.path)
+        .iter()
+        .map(|segment| match segment {
+            RouteSegment::Literal(literal) => literal.as_str(),
+            RouteSegment::Parameter => "{}",
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{} /{}", normalize_route_method(&route.method), template_path)
+}
+
+/// Same method and segment count, every segment pair compatible (equal literals or a `{param}` on either side), and neither
+/// template literal wherever the other is, so no template is a strict refinement that `match_route` could prefer.
+pub(crate) fn routes_overlap_ambiguously(
+    left: &ApiContractRoute,
+    right: &ApiContractRoute,
+) -> bool {
+    let left_segments = parse_route_segments(&left.path);
+    let right_segments = parse_route_segments(&right.path);
+    if normalize_route_method(&left.method) != normalize_route_method(&right.method) || left_segments.len() != right_segments.len() {
+        return false;
+    }
+    let segment_pairs = left_segments.iter().zip(&right_segments).collect::<Vec<_>>();
+    let is_compatible = segment_pairs.iter().all(|segment_pair| match segment_pair {
+        (RouteSegment::Literal(left_literal), RouteSegment::Literal(right_literal)) => left_literal == right_literal,
+        _ => true,
+    });
+    let left_refines_right = segment_pairs
+        .iter()
+        .all(|segment_pair| !matches!(segment_pair, (RouteSegment::Parameter, RouteSegment::Literal(_))));
+    let right_refines_left = segment_pairs
+        .iter()
+        .all(|segment_pair| !matches!(segment_pair, (RouteSegment::Literal(_), RouteSegment::Parameter)));
+    is_compatible && !left_refines_right && !right_refines_left
+}
+
+pub(crate) fn is_route_parameter_segment(segment: &str) -> bool {
+    segment
+        .strip_prefix('{')
+        .and_then(|segment| segment.strip_suffix('}'))
+        .is_some_and(|parameter_name| !parameter_name.is_empty() && !parameter_name.contains(['{', '}']))
+}
+
+fn parse_route_segments(path: &str) -> Vec<RouteSegment> {
+    request_path_segments(path)
+        .into_iter()
+        .map(|segment| {
+            if is_route_parameter_segment(segment) {
+                RouteSegment::Parameter
+            } else {
+                RouteSegment::Literal(segment.to_string())
+            }
+        })
+        .collect()
+}
+
+fn request_path_segments(path: &str) -> Vec<&str> {
+    let path_without_query = path.trim().split(['?', '#']).next().unwrap_or_default();
+    path_without_query
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+fn route_matches(
+    route: &RouteTemplate,
+    request_segments: &[&str],
+) -> bool {
+    route.segments.len() == request_segments.len()
+        && route
+            .segments
+            .iter()
+            .zip(request_segments)
+            .all(|(route_segment, request_segment)| match route_segment {
+                RouteSegment::Literal(literal) => literal == request_segment,
+                RouteSegment::Parameter => true,
+            })
+}
@@ -0,0 +1,65 @@
+use std::io::{self, Read};
+
+use crate::error::MeshRegistryError;
+
+/// Caps how many bytes a registry source may yield, so oversized payloads fail before serde allocates the document.
+#[derive(Debug)]
+pub(crate) struct SourceSizeLimitedReader<R: Read> {
+    inner: R,
+    remaining_bytes: usize,
+    exceeded_limit: bool,
+}
+
+impl<R: Read> SourceSizeLimitedReader<R> {
+    pub(crate) fn new(
+        inner: R,
+        max_source_bytes: usize,
+    ) -> Self {
+        Self {
+            inner,
+            remaining_bytes: max_source_bytes,
+            exceeded_limit: false,
+        }
+    }
+
+    pub(crate) fn exceeded_limit(&self) -> bool {
+        self.exceeded_limit
+    }
+}
+
+impl<R: Read> Read for SourceSizeLimitedReader<R> {
+    fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining_bytes == 0 {
+            let mut probe = [0_u8; 1];
+            if self.inner.read(&mut probe)? == 0 {
+                return Ok(0);
+            }
+            self.exceeded_limit = true;
+            return Err(io::Error::new(io::ErrorKind::FileTooLarge, "registry source exceeds max_source_bytes"));
+        }
+        let read_len = buf.len().min(self.remaining_bytes);
+        let bytes_read = self.inner.read(&mut buf[..read_len])?;
+        self.remaining_bytes -= bytes_read;
+        Ok(bytes_read)
+    }
+}
+
+pub(crate) fn ensure_source_within_limit(
+    source_len: usize,
+    max_source_bytes: usize,
+) -> Result<(), MeshRegistryError> {
+    if source_len > max_source_bytes {
+        return Err(source_size_limit_error(max_source_bytes));
+    }
+    Ok(())
+}
+
+pub(crate) fn source_size_limit_error(max_source_bytes: usize) -> MeshRegistryError {
+    MeshRegistryError::InvalidDocument(format!("registry source exceeds max_source_bytes {}", max_source_bytes))
+}
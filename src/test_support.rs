@@ -0,0 +1,109 @@
+use std::env;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::constants::{
+    ENV_WORLD_BUILDER_ENV, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON, ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH,
+    ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY, ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS,
+};
+use crate::models::ServiceMeshRegistryDocument;
+use crate::registry::ServiceMeshRegistry;
+
+const MANAGED_REGISTRY_ENV_VARS: [&str; 5] = [
+    ENV_WORLD_BUILDER_ENV,
+    ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON,
+    ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH,
+    ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY,
+    ENV_WORLD_BUILDER_SERVICE_MESH_STRICTNESS,
+];
+
+const EMPTY_FOR_TESTS_SERVICE_NAME: &str = "test-support-placeholder";
+const EMPTY_FOR_TESTS_BASE_URL: &str = "http://test-support-placeholder.invalid";
+const EMPTY_FOR_TESTS_API_CONTRACT: &str = "worldbuilder.test-support.placeholder.v1";
+
+static REGISTRY_ENVIRONMENT_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug)]
+pub struct TestRegistryGuard {
+    previous_env_vars: Vec<(&'static str, Option<String>)>,
+    _environment_lock: MutexGuard<'static, ()>,
+}
+
+impl TestRegistryGuard {
+    pub fn empty() -> Self {
+        let environment_lock = REGISTRY_ENVIRONMENT_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let previous_env_vars = MANAGED_REGISTRY_ENV_VARS
+            .iter()
+            .map(|key| (*key, env::var(key).ok()))
+            .collect();
+        for key in MANAGED_REGISTRY_ENV_VARS {
+            remove_env_var(key);
+        }
+        Self {
+            previous_env_vars,
+            _environment_lock: environment_lock,
+        }
+    }
+
+    pub fn from_document(document: &ServiceMeshRegistryDocument) -> Self {
+        let registry_json = serde_json::to_string(document).expect("registry document serializes to json");
+        Self::from_json_str(&registry_json)
+    }
+
+    pub fn from_json_str(registry_json: &str) -> Self {
+        let guard = Self::empty();
+        set_env_var(ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_JSON, registry_json);
+        guard
+    }
+
+    pub fn from_file_path(registry_path: impl AsRef<Path>) -> Self {
+        let guard = Self::empty();
+        set_env_var(ENV_WORLD_BUILDER_SERVICE_MESH_REGISTRY_PATH, registry_path.as_ref().to_string_lossy().as_ref());
+        guard
+    }
+
+    pub fn require_publish_ingress_policy(self) -> Self {
+        set_env_var(ENV_WORLD_BUILDER_SERVICE_MESH_REQUIRE_PUBLISH_INGRESS_POLICY, "true");
+        self
+    }
+}
+
+impl ServiceMeshRegistry {
+    pub fn empty_for_tests() -> Self {
+        Self::single_service(
+            "test-support",
+            EMPTY_FOR_TESTS_SERVICE_NAME,
+            EMPTY_FOR_TESTS_BASE_URL,
+            [EMPTY_FOR_TESTS_API_CONTRACT],
+        )
+        .expect("placeholder registry is valid")
+    }
+}
+
+impl Drop for TestRegistryGuard {
+    fn drop(&mut self) {
+        for (key, previous_value) in &self.previous_env_vars {
+            match previous_value {
+                Some(previous_value) => set_env_var(key, previous_value),
+                None => remove_env_var(key),
+            }
+        }
+    }
+}
+
+fn set_env_var(
+    key: &str,
+    value: &str,
+) {
+    unsafe {
+        env::set_var(key, value);
+    }
+}
+
+fn remove_env_var(key: &str) {
+    unsafe {
+        env::remove_var(key);
+    }
+}
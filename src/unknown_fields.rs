@@ -0,0 +1,59 @@
+use serde_json::Value;
+
+use crate::error::MeshRegistryError;
+use crate::models::ServiceMeshRegistryDocument;
+
+const ANNOTATION_KEY_PREFIX: char = '_';
+const FIELD_ALIASES: [(&str, &str); 1] = [("publish_api_contract", "publish_api_contracts")];
+
+pub(crate) fn ensure_no_unknown_fields(
+    source_value: &Value,
+    document: &ServiceMeshRegistryDocument,
+) -> Result<(), MeshRegistryError> {
+    let known_value = serde_json::to_value(document)?;
+    let mut unknown_field_paths = Vec::<String>::new();
+    collect_unknown_field_paths(source_value, &known_value, "", &mut unknown_field_paths);
+    if unknown_field_paths.is_empty() {
+        return Ok(());
+    }
+    Err(MeshRegistryError::InvalidDocument(format!(
+        "registry document contains unknown fields: {}",
+        unknown_field_paths.join(", ")
+    )))
+}
+
+fn collect_unknown_field_paths(
+    source_value: &Value,
+    known_value: &Value,
+    path: &str,
+    unknown_field_paths: &mut Vec<String>,
+) {
+    match (source_value, known_value) {
+        (Value::Object(source_fields), Value::Object(known_fields)) => {
+            for (field_name, source_field_value) in source_fields {
+                if field_name.starts_with(ANNOTATION_KEY_PREFIX) {
+                    continue;
+                }
+                let field_path = if path.is_empty() {
+                    field_name.clone()
+                } else {
+                    format!("{}.{}", path, field_name)
+                };
+                let canonical_field_name = FIELD_ALIASES
+                    .iter()
+                    .find(|(alias, _)| alias == field_name)
+                    .map_or(field_name.as_str(), |(_, canonical_field_name)| canonical_field_name);
+                match known_fields.get(canonical_field_name) {
+                    Some(known_field_value) => collect_unknown_field_paths(source_field_value, known_field_value, &field_path, unknown_field_paths),
+                    None => unknown_field_paths.push(field_path),
+                }
+            }
+        }
+        (Value::Array(source_items), Value::Array(known_items)) => {
+            for (item_index, (source_item, known_item)) in source_items.iter().zip(known_items).enumerate() {
+                collect_unknown_field_paths(source_item, known_item, &format!("{}[{}]", path, item_index), unknown_field_paths);
+            }
+        }
+        _ => {}
+    }
+}
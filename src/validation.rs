@@ -1,16 +1,140 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use url::Url;
+use serde::Deserialize;
+use url::{Host, Url};
 
+use crate::constants::{MAX_CLIENT_POLICY_RETRIES, is_known_contract};
 use crate::error::MeshRegistryError;
-use crate::models::{PublishIngressPolicy, ServiceMeshRegistryDocument};
+use crate::models::{
+    ApiContractRoute, PublishIngressPolicy, RegistryValidationOptions, ServiceMeshRegistryDocument, ServiceRegistration, ValidationReport, unix_socket_path,
+};
+use crate::registry::{decode_json_document_with_options, strip_registry_source_preamble};
+use crate::route_table::{is_route_parameter_segment, normalize_route_method, route_template_key, routes_overlap_ambiguously};
+use crate::unknown_fields::ensure_no_unknown_fields;
+
+pub fn validate_document(document: &ServiceMeshRegistryDocument) -> Result<(), MeshRegistryError> {
+    validate_registry_document(document, &RegistryValidationOptions::default())
+}
+
+pub fn validate_document_with_options(
+    document: &ServiceMeshRegistryDocument,
+    validation_options: &RegistryValidationOptions,
+) -> Result<(), MeshRegistryError> {
+    validate_registry_document(document, validation_options)
+}
+
+pub fn validate_json_str(registry_json: &str) -> Result<(), MeshRegistryError> {
+    validate_json_str_with_options(registry_json, &RegistryValidationOptions::default())
+}
+
+pub fn validate_json_str_with_options(
+    registry_json: &str,
+    validation_options: &RegistryValidationOptions,
+) -> Result<(), MeshRegistryError> {
+    validate_registry_document(&decode_json_document_with_options(registry_json, validation_options)?, validation_options)
+}
+
+pub fn validate_with_report(document: &ServiceMeshRegistryDocument) -> ValidationReport {
+    validate_with_report_with_options(document, &RegistryValidationOptions::default())
+}
+
+pub fn validate_with_report_with_options(
+    document: &ServiceMeshRegistryDocument,
+    validation_options: &RegistryValidationOptions,
+) -> ValidationReport {
+    collect_registry_document_report(document, validation_options)
+}
+
+pub fn validate_json_str_with_report(
+    registry_json: &str,
+    validation_options: &RegistryValidationOptions,
+) -> ValidationReport {
+    let decoded_document = serde_json::from_str::<serde_json::Value>(strip_registry_source_preamble(registry_json))
+        .and_then(|source_value| ServiceMeshRegistryDocument::deserialize(&source_value).map(|document| (source_value, document)));
+    let (source_value, document) = match decoded_document {
+        Ok(decoded_document) => decoded_document,
+        Err(decode_error) => {
+            return ValidationReport {
+                errors: vec![MeshRegistryError::from(decode_error)],
+                warnings: Vec::new(),
+            };
+        }
+    };
+    let mut report = collect_registry_document_report(&document, validation_options);
+    if let Err(unknown_fields_error) = ensure_no_unknown_fields(&source_value, &document) {
+        report.push_finding(ValidationSeverity::Warning, validation_options.deny_unknown_fields, unknown_fields_error);
+    }
+    report
+}
+
+pub fn validate_all(document: &ServiceMeshRegistryDocument) -> Result<(), Vec<MeshRegistryError>> {
+    validate_all_with_options(document, &RegistryValidationOptions::default())
+}
+
+pub fn validate_all_with_options(
+    document: &ServiceMeshRegistryDocument,
+    validation_options: &RegistryValidationOptions,
+) -> Result<(), Vec<MeshRegistryError>> {
+    let validation_errors = collect_registry_document_report(document, validation_options).errors;
+    if validation_errors.is_empty() {
+        return Ok(());
+    }
+    Err(validation_errors)
+}
+
+pub(crate) fn validate_registry_document(
+    document: &ServiceMeshRegistryDocument,
+    validation_options: &RegistryValidationOptions,
+) -> Result<(), MeshRegistryError> {
+    match collect_registry_document_report(document, validation_options)
+        .errors
+        .into_iter()
+        .next()
+    {
+        Some(validation_error) => Err(validation_error),
+        None => Ok(()),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ValidationSeverity {
+    Error,
+    Warning,
+}
+
+impl ValidationReport {
+    fn push_finding(
+        &mut self,
+        default_severity: ValidationSeverity,
+        escalate_warnings: bool,
+        finding: MeshRegistryError,
+    ) {
+        match default_severity {
+            ValidationSeverity::Warning if !escalate_warnings => self.warnings.push(finding),
+            ValidationSeverity::Error | ValidationSeverity::Warning => self.errors.push(finding),
+        }
+    }
+}
+
+fn collect_registry_document_report(
+    document: &ServiceMeshRegistryDocument,
+    validation_options: &RegistryValidationOptions,
+) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    if let Some(size_limit_error) = registry_document_size_limit_error(document, validation_options) {
+        report.errors.push(size_limit_error);
+        return report;
+    }
 
-pub(crate) fn validate_registry_document(document: &ServiceMeshRegistryDocument) -> Result<(), MeshRegistryError> {
     if document.version.trim().is_empty() {
-        return Err(MeshRegistryError::InvalidDocument("version must not be empty".to_string()));
+        report
+            .errors
+            .push(MeshRegistryError::InvalidDocument("version must not be empty".to_string()));
     }
     if document.services.is_empty() {
-        return Err(MeshRegistryError::InvalidDocument("at least one service registration is required".to_string()));
+        report
+            .errors
+            .push(MeshRegistryError::InvalidDocument("at least one service registration is required".to_string()));
     }
 
     let mut service_names = HashSet::<String>::new();
@@ -19,41 +143,143 @@ pub(crate) fn validate_registry_document(document: &ServiceMeshRegistryDocument)
     for service in &document.services {
         let service_name = service.service_name.trim();
         if service_name.is_empty() {
-            return Err(MeshRegistryError::InvalidDocument("service_name must not be empty".to_string()));
-        }
-        if !service_names.insert(service_name.to_string()) {
-            return Err(MeshRegistryError::InvalidDocument(format!("service_name '{}' is duplicated", service_name)));
+            report
+                .errors
+                .push(MeshRegistryError::InvalidDocument("service_name must not be empty".to_string()));
+        } else if !service_names.insert(service_name.to_string()) {
+            report
+                .errors
+                .push(MeshRegistryError::InvalidDocument(format!("service_name '{}' is duplicated", service_name)));
         }
 
-        let parsed_base_url = Url::parse(service.base_url.trim()).map_err(|parse_error| {
-            MeshRegistryError::InvalidDocument(format!(
-                "service '{}' base_url '{}' is invalid: {}",
-                service_name, service.base_url, parse_error
-            ))
-        })?;
-        if parsed_base_url.host_str().is_none() {
-            return Err(MeshRegistryError::InvalidDocument(format!(
-                "service '{}' base_url '{}' must include a host",
-                service_name, service.base_url
+        let service_label = format!("service '{}'", service_name);
+        collect_base_url_findings(&service_label, &service.base_url, validation_options, &mut report);
+        report
+            .errors
+            .extend(validate_metadata_keys(&service_label, &service.metadata).err());
+        for (instance_index, instance) in service.instances.iter().enumerate() {
+            let instance_label = format!("service '{}' instances[{}]", service_name, instance_index);
+            collect_base_url_findings(&instance_label, &instance.base_url, validation_options, &mut report);
+            report
+                .errors
+                .extend(validate_metadata_keys(&instance_label, &instance.metadata).err());
+        }
+        for (failover_index, failover_base_url) in service.failover_base_urls.iter().enumerate() {
+            let failover_label = format!("service '{}' failover_base_urls[{}]", service_name, failover_index);
+            collect_base_url_findings(&failover_label, failover_base_url, validation_options, &mut report);
+        }
+        if service.weight == 0 && service.instances.iter().all(|instance| instance.weight == 0) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "service '{}' must have at least one endpoint with nonzero weight",
+                service_name
+            )));
+        }
+        if let Some(health_path) = &service.health_path {
+            let health_path = health_path.trim();
+            let health_path_problem = if !health_path.starts_with('/') {
+                Some("must start with '/'")
+            } else if health_path.starts_with("//") {
+                Some("must not start with '//'")
+            } else if health_path.contains(['?', '#']) {
+                Some("must not contain a query or fragment")
+            } else {
+                None
+            };
+            if let Some(health_path_problem) = health_path_problem {
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                    "service '{}' health_path '{}' {}",
+                    service_name, health_path, health_path_problem
+                )));
+            }
+        }
+        if let Some(client_policy) = &service.client_policy {
+            if client_policy.connect_timeout_ms == 0 || client_policy.request_timeout_ms == 0 {
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                    "service '{}' client_policy timeouts must be nonzero",
+                    service_name
+                )));
+            }
+            if client_policy.max_retries > MAX_CLIENT_POLICY_RETRIES {
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                    "service '{}' client_policy.max_retries {} exceeds the limit of {}",
+                    service_name, client_policy.max_retries, MAX_CLIENT_POLICY_RETRIES
+                )));
+            }
+        }
+        if service
+            .environments
+            .iter()
+            .any(|environment| environment.trim().is_empty())
+        {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "service '{}' environments must not contain empty entries",
+                service_name
             )));
         }
         if service.api_contracts.is_empty() {
-            return Err(MeshRegistryError::InvalidDocument(format!(
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
                 "service '{}' must register at least one api contract",
                 service_name
             )));
         }
 
+        if let Some(sensitive_api_contract) = service
+            .api_contracts
+            .iter()
+            .map(|api_contract| api_contract.trim())
+            .find(|api_contract| {
+                validation_options
+                    .https_required_api_contract_prefixes
+                    .iter()
+                    .any(|sensitive_prefix| api_contract.starts_with(sensitive_prefix))
+            })
+        {
+            let non_https_base_url = std::iter::once(service.base_url.as_str())
+                .chain(
+                    service
+                        .instances
+                        .iter()
+                        .map(|instance| instance.base_url.as_str()),
+                )
+                .chain(service.failover_base_urls.iter().map(String::as_str))
+                .find(|base_url| !is_https_base_url(base_url));
+            if let Some(non_https_base_url) = non_https_base_url {
+                report.push_finding(
+                    ValidationSeverity::Error,
+                    validation_options.strict,
+                    MeshRegistryError::InvalidDocument(format!(
+                        "service '{}' serves sensitive api contract '{}' and base_url '{}' must use https",
+                        service_name,
+                        sensitive_api_contract,
+                        non_https_base_url.trim()
+                    )),
+                );
+            }
+        }
+
         for api_contract in &service.api_contracts {
             let normalized_api_contract = api_contract.trim();
             if normalized_api_contract.is_empty() {
-                return Err(MeshRegistryError::InvalidDocument(format!(
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
                     "service '{}' has an empty api contract entry",
                     service_name
                 )));
+                continue;
+            }
+            if normalized_api_contract.chars().any(char::is_whitespace) {
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                    "service '{}' api contract '{}' must not contain internal whitespace",
+                    service_name, normalized_api_contract
+                )));
+            }
+            if validation_options.deny_unknown_api_contracts && !is_known_contract(normalized_api_contract) {
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                    "service '{}' api contract '{}' is not a known api contract and deny_unknown_api_contracts is enabled",
+                    service_name, normalized_api_contract
+                )));
             }
             if !api_contracts.insert(normalized_api_contract.to_string()) {
-                return Err(MeshRegistryError::InvalidDocument(format!(
+                report.errors.push(MeshRegistryError::InvalidDocument(format!(
                     "api contract '{}' is registered by multiple services",
                     normalized_api_contract
                 )));
@@ -61,31 +287,391 @@ pub(crate) fn validate_registry_document(document: &ServiceMeshRegistryDocument)
         }
     }
 
+    collect_scheme_only_base_url_findings(&document.services, validation_options, &mut report);
+    collect_api_contract_alias_errors(&document.api_contract_aliases, &api_contracts, &mut report.errors);
+    if let Some(default_service) = &document.default_service
+        && !service_names.contains(default_service.trim())
+    {
+        report.errors.push(MeshRegistryError::InvalidDocument(format!(
+            "default_service '{}' is not a registered service",
+            default_service.trim()
+        )));
+    }
+    collect_api_contract_route_errors(&document.api_contract_routes, &api_contracts, &mut report.errors);
+    for (api_contract, enabled_when_env) in &document.api_contract_enabled_when_env {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract enabled_when_env targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+        if !is_conventional_env_var_name(enabled_when_env.trim()) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract '{}' enabled_when_env '{}' must match [A-Z][A-Z0-9_]*",
+                normalized_api_contract,
+                enabled_when_env.trim()
+            )));
+        }
+    }
+    for (api_contract, content_type) in &document.api_contract_content_types {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract content_type targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+        if !is_plausible_mime_type(content_type.trim()) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract '{}' content_type '{}' must be a type/subtype media type",
+                normalized_api_contract,
+                content_type.trim()
+            )));
+        }
+    }
+    for (api_contract, rate_limit) in &document.api_contract_rate_limits {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract rate_limit targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+        if rate_limit.requests_per_minute == 0 || rate_limit.burst == 0 {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract '{}' rate_limit requests_per_minute and burst must be greater than zero",
+                normalized_api_contract
+            )));
+        }
+    }
+    for (api_contract, environments) in &document.api_contract_environments {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract environments targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+        if environments
+            .iter()
+            .any(|environment| environment.trim().is_empty())
+        {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract '{}' environments must not contain empty entries",
+                normalized_api_contract
+            )));
+        }
+    }
+    for api_contract in document.api_contract_requires_auth.keys() {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract requires_auth targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+    }
+    for api_contract in document.api_contract_kinds.keys() {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract kind targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+    }
+
     if let Some(publish_ingress_policy) = &document.publish_ingress_policy {
-        validate_publish_ingress_policy(publish_ingress_policy)?;
+        collect_publish_ingress_policy_findings(publish_ingress_policy, &service_names, &api_contracts, validation_options, &mut report);
+    }
+
+    report
+}
+
+pub(crate) fn validate_base_url(
+    owner_label: &str,
+    base_url: &str,
+    validation_options: &RegistryValidationOptions,
+) -> Result<(), MeshRegistryError> {
+    let mut report = ValidationReport::default();
+    collect_base_url_findings(owner_label, base_url, validation_options, &mut report);
+    match report.errors.into_iter().next() {
+        Some(validation_error) => Err(validation_error),
+        None => Ok(()),
+    }
+}
+
+fn collect_base_url_findings(
+    owner_label: &str,
+    base_url: &str,
+    validation_options: &RegistryValidationOptions,
+    report: &mut ValidationReport,
+) {
+    report
+        .errors
+        .extend(base_url_error(owner_label, base_url, validation_options).err());
+    if unix_socket_path(base_url).is_none()
+        && let Ok(parsed_base_url) = Url::parse(base_url.trim())
+        && parsed_base_url
+            .host()
+            .is_some_and(|base_url_host| is_loopback_host(&base_url_host))
+    {
+        report.push_finding(
+            ValidationSeverity::Warning,
+            validation_options.strict,
+            MeshRegistryError::InvalidDocument(format!(
+                "{} base_url '{}' must not target a loopback host in strict mode",
+                owner_label, base_url
+            )),
+        );
+    }
+}
+
+fn base_url_error(
+    owner_label: &str,
+    base_url: &str,
+    validation_options: &RegistryValidationOptions,
+) -> Result<(), MeshRegistryError> {
+    if let Some(socket_path) = unix_socket_path(base_url) {
+        if !validation_options.allow_unix_socket_targets {
+            return Err(MeshRegistryError::InvalidDocument(format!(
+                "{} base_url '{}' targets a unix socket but allow_unix_socket_targets is disabled",
+                owner_label, base_url
+            )));
+        }
+        if socket_path.len() <= 1
+            || socket_path.ends_with('/')
+            || socket_path
+                .chars()
+                .any(|character| character.is_whitespace() || character == '?' || character == '#')
+        {
+            return Err(MeshRegistryError::InvalidDocument(format!(
+                "{} base_url '{}' must name an absolute socket file path",
+                owner_label, base_url
+            )));
+        }
+        return Ok(());
+    }
+    let parsed_base_url = Url::parse(base_url.trim())
+        .map_err(|parse_error| MeshRegistryError::InvalidDocument(format!("{} base_url '{}' is invalid: {}", owner_label, base_url, parse_error)))?;
+    if parsed_base_url.host().is_none() {
+        return Err(MeshRegistryError::InvalidDocument(format!(
+            "{} base_url '{}' must include a host",
+            owner_label, base_url
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_metadata_keys(
+    owner_label: &str,
+    metadata: &HashMap<String, String>,
+) -> Result<(), MeshRegistryError> {
+    if metadata
+        .keys()
+        .any(|metadata_key| metadata_key.trim().is_empty())
+    {
+        return Err(MeshRegistryError::InvalidDocument(format!("{} has an empty metadata key", owner_label)));
     }
 
     Ok(())
 }
 
-fn validate_publish_ingress_policy(publish_ingress_policy: &PublishIngressPolicy) -> Result<(), MeshRegistryError> {
-    if publish_ingress_policy.policy_owner_product.trim().is_empty() {
-        return Err(MeshRegistryError::InvalidDocument(
+fn registry_document_size_limit_error(
+    document: &ServiceMeshRegistryDocument,
+    validation_options: &RegistryValidationOptions,
+) -> Option<MeshRegistryError> {
+    if document.services.len() > validation_options.max_services {
+        return Some(MeshRegistryError::InvalidDocument(format!(
+            "registry declares {} services, exceeding max_services {}",
+            document.services.len(),
+            validation_options.max_services
+        )));
+    }
+    let total_api_contracts = document
+        .services
+        .iter()
+        .map(|service| service.api_contracts.len())
+        .sum::<usize>();
+    if total_api_contracts > validation_options.max_total_contracts {
+        return Some(MeshRegistryError::InvalidDocument(format!(
+            "registry declares {} api contracts, exceeding max_total_contracts {}",
+            total_api_contracts, validation_options.max_total_contracts
+        )));
+    }
+    None
+}
+
+fn collect_api_contract_route_errors(
+    api_contract_routes: &BTreeMap<String, ApiContractRoute>,
+    api_contracts: &HashSet<String>,
+    validation_errors: &mut Vec<MeshRegistryError>,
+) {
+    let mut route_templates = HashMap::<String, &str>::new();
+    let mut well_formed_routes = Vec::<(&str, &ApiContractRoute)>::new();
+    for (api_contract, route) in api_contract_routes {
+        let normalized_api_contract = api_contract.trim();
+        if !api_contracts.contains(normalized_api_contract) {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract route targets unregistered api contract '{}'",
+                normalized_api_contract
+            )));
+        }
+        let normalized_method = route.method.trim();
+        if normalized_method.is_empty()
+            || !normalized_method
+                .chars()
+                .all(|character| character.is_ascii_alphabetic())
+        {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract '{}' route method '{}' must be an HTTP method name",
+                normalized_api_contract, normalized_method
+            )));
+        }
+        let normalized_path = route.path.trim();
+        let has_malformed_segment = normalized_path
+            .split('/')
+            .any(|segment| segment.contains(['{', '}']) && !is_route_parameter_segment(segment));
+        if !normalized_path.starts_with('/') || has_malformed_segment {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract '{}' route path '{}' must start with '/' and use whole-segment '{{param}}' placeholders",
+                normalized_api_contract, normalized_path
+            )));
+            continue;
+        }
+        if let Some(existing_api_contract) = route_templates.insert(route_template_key(route), normalized_api_contract) {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contracts '{}' and '{}' declare the same route '{} {}'",
+                existing_api_contract,
+                normalized_api_contract,
+                normalize_route_method(&route.method),
+                normalized_path
+            )));
+        } else if let Some((existing_api_contract, existing_route)) = well_formed_routes
+            .iter()
+            .find(|(_, existing_route)| routes_overlap_ambiguously(existing_route, route))
+        {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contracts '{}' and '{}' declare overlapping routes '{} {}' and '{} {}'",
+                existing_api_contract,
+                normalized_api_contract,
+                normalize_route_method(&existing_route.method),
+                existing_route.path.trim(),
+                normalize_route_method(&route.method),
+                normalized_path
+            )));
+        }
+        well_formed_routes.push((normalized_api_contract, route));
+    }
+}
+
+fn collect_api_contract_alias_errors(
+    api_contract_aliases: &BTreeMap<String, String>,
+    api_contracts: &HashSet<String>,
+    validation_errors: &mut Vec<MeshRegistryError>,
+) {
+    let mut alias_names = HashSet::<String>::new();
+    for (alias, canonical_api_contract) in api_contract_aliases {
+        let normalized_alias = alias.trim();
+        if normalized_alias.is_empty() {
+            validation_errors.push(MeshRegistryError::InvalidDocument("api_contract_aliases contains an empty alias".to_string()));
+            continue;
+        }
+        if api_contracts.contains(normalized_alias) {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract alias '{}' collides with a registered api contract",
+                normalized_alias
+            )));
+        }
+        if !alias_names.insert(normalized_alias.to_string()) {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract alias '{}' is duplicated",
+                normalized_alias
+            )));
+        }
+
+        let normalized_canonical_api_contract = canonical_api_contract.trim();
+        if !api_contracts.contains(normalized_canonical_api_contract) {
+            validation_errors.push(MeshRegistryError::InvalidDocument(format!(
+                "api contract alias '{}' targets unregistered api contract '{}'",
+                normalized_alias, normalized_canonical_api_contract
+            )));
+        }
+    }
+}
+
+fn collect_publish_ingress_policy_findings(
+    publish_ingress_policy: &PublishIngressPolicy,
+    registered_service_names: &HashSet<String>,
+    registered_api_contracts: &HashSet<String>,
+    validation_options: &RegistryValidationOptions,
+    report: &mut ValidationReport,
+) {
+    let policy_owner_product = publish_ingress_policy.policy_owner_product.trim();
+    if policy_owner_product.is_empty() {
+        report.errors.push(MeshRegistryError::InvalidDocument(
             "publish_ingress_policy.policy_owner_product must not be empty".to_string(),
         ));
+    } else if let Some(known_policy_owner_products) = validation_options.known_policy_owner_products
+        && !registered_service_names.contains(policy_owner_product)
+        && !known_policy_owner_products
+            .iter()
+            .any(|known_policy_owner_product| known_policy_owner_product.trim() == policy_owner_product)
+    {
+        report.push_finding(
+            ValidationSeverity::Warning,
+            validation_options.strict,
+            MeshRegistryError::InvalidDocument(format!(
+                "publish_ingress_policy.policy_owner_product '{}' is neither a registered service nor a known product",
+                policy_owner_product
+            )),
+        );
     }
-    if publish_ingress_policy.publish_api_contract.trim().is_empty() {
-        return Err(MeshRegistryError::InvalidDocument(
-            "publish_ingress_policy.publish_api_contract must not be empty".to_string(),
+    if publish_ingress_policy.publish_api_contracts.is_empty() {
+        report.errors.push(MeshRegistryError::InvalidDocument(
+            "publish_ingress_policy.publish_api_contracts must include at least one api contract".to_string(),
         ));
     }
+    let mut publish_api_contracts = HashSet::<&str>::new();
+    for publish_api_contract in &publish_ingress_policy.publish_api_contracts {
+        let normalized_publish_api_contract = publish_api_contract.trim();
+        if normalized_publish_api_contract.is_empty() {
+            report.errors.push(MeshRegistryError::InvalidDocument(
+                "publish_ingress_policy.publish_api_contracts contains an empty api contract".to_string(),
+            ));
+        } else if !registered_api_contracts.contains(normalized_publish_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "publish_ingress_policy.publish_api_contracts targets unregistered api contract '{}'",
+                normalized_publish_api_contract
+            )));
+        } else if !publish_api_contracts.insert(normalized_publish_api_contract) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "publish_ingress_policy.publish_api_contracts contains duplicate api contract '{}'",
+                normalized_publish_api_contract
+            )));
+        }
+    }
     if publish_ingress_policy.default_max_body_bytes == 0 {
-        return Err(MeshRegistryError::InvalidDocument(
+        report.errors.push(MeshRegistryError::InvalidDocument(
             "publish_ingress_policy.default_max_body_bytes must be greater than zero".to_string(),
         ));
     }
+    if publish_ingress_policy.default_max_body_bytes > validation_options.max_body_bytes_sanity_ceiling {
+        report.push_finding(
+            ValidationSeverity::Warning,
+            validation_options.strict,
+            MeshRegistryError::InvalidDocument(format!(
+                "publish_ingress_policy owned by '{}' default_max_body_bytes {} exceeds sanity ceiling {} bytes",
+                publish_ingress_policy.policy_owner_product.trim(),
+                publish_ingress_policy.default_max_body_bytes,
+                validation_options.max_body_bytes_sanity_ceiling
+            )),
+        );
+    }
     if publish_ingress_policy.required_hops.is_empty() {
-        return Err(MeshRegistryError::InvalidDocument(
+        report.errors.push(MeshRegistryError::InvalidDocument(
             "publish_ingress_policy.required_hops must include at least one hop".to_string(),
         ));
     }
@@ -95,7 +681,7 @@ fn validate_publish_ingress_policy(publish_ingress_policy: &PublishIngressPolicy
         .trim()
         .is_empty()
     {
-        return Err(MeshRegistryError::InvalidDocument(
+        report.errors.push(MeshRegistryError::InvalidDocument(
             "publish_ingress_policy.observability.rejection_metric_name must not be empty".to_string(),
         ));
     }
@@ -104,56 +690,216 @@ fn validate_publish_ingress_policy(publish_ingress_policy: &PublishIngressPolicy
         .rejection_log_fields
         .is_empty()
     {
-        return Err(MeshRegistryError::InvalidDocument(
+        report.errors.push(MeshRegistryError::InvalidDocument(
             "publish_ingress_policy.observability.rejection_log_fields must include at least one field".to_string(),
         ));
     }
+    let missing_rejection_log_fields = validation_options
+        .mandatory_rejection_log_fields
+        .iter()
+        .filter(|mandatory_field| {
+            !publish_ingress_policy
+                .observability
+                .rejection_log_fields
+                .iter()
+                .any(|rejection_log_field| rejection_log_field.trim() == **mandatory_field)
+        })
+        .copied()
+        .collect::<Vec<_>>();
+    if !missing_rejection_log_fields.is_empty() {
+        report.push_finding(
+            ValidationSeverity::Warning,
+            validation_options.strict,
+            MeshRegistryError::InvalidDocument(format!(
+                "publish_ingress_policy.observability.rejection_log_fields is missing mandatory fields in strict mode: {}",
+                missing_rejection_log_fields.join(", ")
+            )),
+        );
+    }
 
     let mut hop_names = HashSet::<String>::new();
     let mut hop_env_var_names = HashSet::<String>::new();
     for required_hop in &publish_ingress_policy.required_hops {
         let hop_name = required_hop.hop_name.trim();
         if hop_name.is_empty() {
-            return Err(MeshRegistryError::InvalidDocument(
+            report.errors.push(MeshRegistryError::InvalidDocument(
                 "publish_ingress_policy.required_hops[].hop_name must not be empty".to_string(),
             ));
-        }
-        if !hop_names.insert(hop_name.to_string()) {
-            return Err(MeshRegistryError::InvalidDocument(format!(
+        } else if !hop_names.insert(hop_name.to_string()) {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
                 "publish_ingress_policy.required_hops contains duplicate hop '{}'",
                 hop_name
             )));
         }
 
         if required_hop.product.trim().is_empty() {
-            return Err(MeshRegistryError::InvalidDocument(format!(
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
                 "publish_ingress_policy.required_hops['{}'].product must not be empty",
                 hop_name
             )));
         }
 
+        if let Some(min_body_bytes) = required_hop.min_body_bytes
+            && min_body_bytes < publish_ingress_policy.default_max_body_bytes
+        {
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
+                "publish_ingress_policy.required_hops['{}'].min_body_bytes {} must be at least default_max_body_bytes {}",
+                hop_name, min_body_bytes, publish_ingress_policy.default_max_body_bytes
+            )));
+        }
+
         let max_body_bytes_env_var = required_hop.max_body_bytes_env_var.trim();
         if max_body_bytes_env_var.is_empty() {
-            return Err(MeshRegistryError::InvalidDocument(format!(
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
                 "publish_ingress_policy.required_hops['{}'].max_body_bytes_env_var must not be empty",
                 hop_name
             )));
+            continue;
+        }
+        if !is_conventional_env_var_name(max_body_bytes_env_var) {
+            report.push_finding(
+                ValidationSeverity::Warning,
+                validation_options.strict,
+                MeshRegistryError::InvalidDocument(format!(
+                    "publish_ingress_policy.required_hops['{}'].max_body_bytes_env_var '{}' must match [A-Z][A-Z0-9_]*",
+                    hop_name, max_body_bytes_env_var
+                )),
+            );
         }
         if !hop_env_var_names.insert(max_body_bytes_env_var.to_string()) {
-            return Err(MeshRegistryError::InvalidDocument(format!(
+            report.errors.push(MeshRegistryError::InvalidDocument(format!(
                 "publish_ingress_policy.required_hops uses duplicate max_body_bytes_env_var '{}'",
                 max_body_bytes_env_var
             )));
         }
     }
 
-    for rejection_log_field in &publish_ingress_policy.observability.rejection_log_fields {
-        if rejection_log_field.trim().is_empty() {
-            return Err(MeshRegistryError::InvalidDocument(
-                "publish_ingress_policy.observability.rejection_log_fields contains an empty field".to_string(),
-            ));
+    collect_publish_ingress_hop_order_findings(publish_ingress_policy, validation_options, report);
+
+    if publish_ingress_policy
+        .observability
+        .rejection_log_fields
+        .iter()
+        .any(|rejection_log_field| rejection_log_field.trim().is_empty())
+    {
+        report.errors.push(MeshRegistryError::InvalidDocument(
+            "publish_ingress_policy.observability.rejection_log_fields contains an empty field".to_string(),
+        ));
+    }
+}
+
+fn collect_scheme_only_base_url_findings(
+    services: &[ServiceRegistration],
+    validation_options: &RegistryValidationOptions,
+    report: &mut ValidationReport,
+) {
+    // `Url::port` is `None` for a scheme's default port, so `http://host` and `https://host:443` still collide while
+    // explicitly distinct ports (`http://host:8080` vs `https://host:8443`) do not.
+    let mut services_by_host_and_path = HashMap::<(String, Option<u16>, String), (&str, String)>::new();
+    for service in services {
+        if unix_socket_path(&service.base_url).is_some() {
+            continue;
+        }
+        let Ok(parsed_base_url) = Url::parse(service.base_url.trim()) else {
+            continue;
+        };
+        let Some(base_url_host) = parsed_base_url.host_str() else {
+            continue;
+        };
+        let service_name = service.service_name.trim();
+        let host_and_path = (base_url_host.to_ascii_lowercase(), parsed_base_url.port(), parsed_base_url.path().to_string());
+        match services_by_host_and_path.get(&host_and_path) {
+            Some((first_service_name, first_scheme)) if first_scheme != parsed_base_url.scheme() => report.push_finding(
+                ValidationSeverity::Warning,
+                validation_options.strict,
+                MeshRegistryError::InvalidDocument(format!(
+                    "services '{}' and '{}' share base_url host '{}' but differ only in scheme ('{}' vs '{}')",
+                    first_service_name,
+                    service_name,
+                    host_and_path.0,
+                    first_scheme,
+                    parsed_base_url.scheme()
+                )),
+            ),
+            Some(_) => {}
+            None => {
+                services_by_host_and_path.insert(host_and_path, (service_name, parsed_base_url.scheme().to_string()));
+            }
         }
     }
+}
 
-    Ok(())
+fn collect_publish_ingress_hop_order_findings(
+    publish_ingress_policy: &PublishIngressPolicy,
+    validation_options: &RegistryValidationOptions,
+    report: &mut ValidationReport,
+) {
+    let canonical_hop_order = validation_options.canonical_publish_ingress_hop_order;
+    let mut previous_canonical_hop: Option<(usize, &str)> = None;
+    for required_hop in &publish_ingress_policy.required_hops {
+        let hop_name = required_hop.hop_name.trim();
+        let Some(canonical_index) = canonical_hop_order
+            .iter()
+            .position(|canonical_hop| canonical_hop.trim() == hop_name)
+        else {
+            continue;
+        };
+        if let Some((previous_canonical_index, previous_hop_name)) = previous_canonical_hop
+            && canonical_index < previous_canonical_index
+        {
+            report.push_finding(
+                ValidationSeverity::Warning,
+                validation_options.strict,
+                MeshRegistryError::InvalidDocument(format!(
+                    "publish_ingress_policy.required_hops lists '{}' after '{}', expected canonical order: {}",
+                    hop_name,
+                    previous_hop_name,
+                    canonical_hop_order.join(" -> ")
+                )),
+            );
+            continue;
+        }
+        previous_canonical_hop = Some((canonical_index, hop_name));
+    }
+}
+
+fn is_loopback_host(host: &Host<&str>) -> bool {
+    match host {
+        Host::Domain(domain) => domain.trim_end_matches('.').eq_ignore_ascii_case("localhost"),
+        Host::Ipv4(address) => address.is_loopback(),
+        Host::Ipv6(address) => address.is_loopback(),
+    }
+}
+
+fn is_https_base_url(base_url: &str) -> bool {
+    Url::parse(base_url.trim()).is_ok_and(|parsed_base_url| parsed_base_url.scheme() == "https")
+}
+
+fn is_plausible_mime_type(content_type: &str) -> bool {
+    let mut content_type_parts = content_type.split(';');
+    let essence = content_type_parts.next().unwrap_or_default().trim();
+    let is_mime_token = |token: &str| {
+        !token.is_empty()
+            && token
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "!#$&^_.+-".contains(character))
+    };
+    let essence_is_valid = essence
+        .split_once('/')
+        .is_some_and(|(media_type, media_subtype)| is_mime_token(media_type) && is_mime_token(media_subtype));
+    essence_is_valid
+        && content_type_parts.all(|parameter| {
+            parameter
+                .trim()
+                .split_once('=')
+                .is_some_and(|(parameter_name, parameter_value)| is_mime_token(parameter_name) && !parameter_value.is_empty())
+        })
+}
+
+fn is_conventional_env_var_name(env_var_name: &str) -> bool {
+    let mut characters = env_var_name.chars();
+    let Some(first_character) = characters.next() else {
+        return false;
+    };
+    first_character.is_ascii_uppercase() && characters.all(|character| character.is_ascii_uppercase() || character.is_ascii_digit() || character == '_')
 }